@@ -6,6 +6,7 @@ pub use hdk_extensions;
 pub use coop_content_types;
 pub use coop_content_types::*;
 
+use std::collections::BTreeMap;
 use hdi_extensions::trace_origin_root;
 use hdk::prelude::*;
 use holo_hash::{
@@ -49,6 +50,19 @@ where
 //
 // CSR Input Structs
 //
+/// A point in a group's history used to scope a query
+///
+/// When a query is scoped to a past point, group state is resolved from the matching group
+/// revision and any contribution links created after the cutoff are ignored.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsOf {
+    /// A specific group revision (the group's create or update action)
+    Revision(ActionHash),
+    /// The group revision that was the latest at the given time
+    Timestamp(Timestamp),
+}
+
 /// Input required for registering new content to a group
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateContributionLinkInput {
@@ -66,6 +80,10 @@ pub struct CreateContributionUpdateLinkInput {
     pub content_id: AnyLinkableHash,
     pub content_prev: AnyLinkableHash,
     pub content_next: AnyLinkableHash,
+    /// Reject the update with [`CoopContentError::ContentLocked`] if another agent holds a lock
+    /// on the content
+    #[serde(default)]
+    pub respect_locks: bool,
 }
 
 /// Input required for initializing a contributions anchor entry
@@ -84,619 +102,2148 @@ pub struct GetAllGroupContentInput {
     pub content_type: Option<String>,
     pub content_base: Option<String>,
     pub full_trace: Option<bool>,
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+    /// Include pending content proposals (defaults to the approved view)
+    #[serde(default)]
+    pub include_proposals: bool,
 }
 
+/// Input for following all content evolutions in a group using shortcuts
+///
+/// Serialized as `(group_id, content_type, content_base, as_of, include_proposals)`; the last two
+/// can be left out so that the original 3-tuple input is still accepted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetAllGroupContentShortcutsInput(
+    pub ActionHash,
+    pub Option<String>,
+    pub Option<String>,
+    #[serde(default)]
+    pub Option<AsOf>,
+    #[serde(default)]
+    pub bool,
+);
+
 /// Input for following a single content's evolution in a group
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GetGroupContentInput {
     pub group_id: ActionHash,
     pub content_id: AnyLinkableHash,
     pub full_trace: Option<bool>,
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+    /// Include pending content proposals (defaults to the approved view)
+    #[serde(default)]
+    pub include_proposals: bool,
 }
 
+/// Input for building a content tree from `content_base` relations in a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetContentTreeInput {
+    pub group_id: ActionHash,
+    /// The content ID at the root of the tree
+    pub content_id: AnyLinkableHash,
+    /// The content type of the descendants (eg. `comment`)
+    pub content_type: String,
+    /// The maximum number of levels below the root
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    /// The maximum number of children per node
+    #[serde(default)]
+    pub max_breadth: Option<usize>,
+    pub full_trace: Option<bool>,
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+}
 
+/// Input for getting a group's state at a point in its history
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetGroupInput {
+    pub group_id: ActionHash,
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+}
 
-//
-// A trait for determining a group state
-//
-/// A trait for determining an entry's group reference
-pub trait GroupRef {
-    fn group_ref(&self) -> (ActionHash, ActionHash);
+/// Input for getting a group's activity feed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetGroupActivityInput {
+    pub group_id: ActionHash,
+    /// Only include content events for this content type (excludes membership events)
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// Only include events authored by this agent
+    #[serde(default)]
+    pub author: Option<AgentPubKey>,
+    /// Only include events that happened after this time
+    #[serde(default)]
+    pub since: Option<Timestamp>,
 }
 
-impl GroupRef for (ActionHash, ActionHash) {
-    fn group_ref(&self) -> (ActionHash, ActionHash) {
-        self.to_owned()
-    }
+
+/// Input for getting an agent's contributions
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetAgentContributionsInput {
+    pub agent: AgentPubKey,
+    /// Limit results to a single group (defaults to all of the agent's groups)
+    #[serde(default)]
+    pub group_id: Option<ActionHash>,
 }
 
-/// Easily-implement the [`GroupRef`] trait
-///
-/// When using a single field, the 2 [`ActionHash`] tuple order must be `(ID, revision)`
-///
-/// #### Examples
-///
-/// ##### Example: Single Field
-/// ```ignore
-/// struct PostEntry {
-///     pub message: String,
-///     pub group_ref: (ActionHash, ActionHash),
-/// }
-/// common_fields!( PostEntry, group_ref );
-/// ```
-///
-/// ##### Example: Separate Fields
-/// ```ignore
-/// struct PostEntry {
-///     pub message: String,
-///
-///     pub group_id: ActionHash,
-///     pub group_rev: ActionHash,
-/// }
-/// common_fields!( PostEntry, group_id, group_rev );
-/// ```
+
+/// Input for searching the group directory
 ///
-/// ##### Example: Separate Struct
-/// ```ignore
-/// struct GroupRef {
-///     pub id: ActionHash,
-///     pub rev: ActionHash,
-/// }
+/// When both filters are given, groups must match both.  With no filters, every listed group is
+/// returned.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GetDirectoryGroupsInput {
+    /// A case-insensitive name prefix (at least 3 characters)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A case-insensitive tag
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+
+/// Input for claiming or transferring a group handle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupHandleInput {
+    pub group_id: ActionHash,
+    pub handle: String,
+}
+
+
+/// Input for requesting to join a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateJoinRequestInput {
+    pub group_id: ActionHash,
+    /// An optional note for the group admins
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+
+/// Input for identifying a content lock
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentLockInput {
+    pub group_id: ActionHash,
+    pub content_id: AnyLinkableHash,
+}
+
+
+/// Input for taking (or renewing) a content lock
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LockContentInput {
+    pub group_id: ActionHash,
+    pub content_id: AnyLinkableHash,
+    /// How long (in seconds) the lock lasts (defaults to [`CONTENT_LOCK_DEFAULT_DURATION`])
+    #[serde(default)]
+    pub duration: Option<u64>,
+}
+
+
+/// Input for getting the pending content proposals of a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetContentProposalsInput {
+    pub group_id: ActionHash,
+    /// Limit results to a single content ID
+    #[serde(default)]
+    pub content_id: Option<AnyLinkableHash>,
+}
+
+
+/// Input for proposing changes to a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProposeGroupChangeInput {
+    pub group_id: ActionHash,
+    pub changes: GovernanceChanges,
+    /// An optional explanation for the voters
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+
+/// Input for voting on a governance proposal
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GovernanceVoteInput {
+    pub proposal_id: ActionHash,
+    pub approve: bool,
+}
+
+
+/// Input for continuing the archive copy of removed members' contributions
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContinueArchiveInput {
+    /// The group revision that removed the members
+    pub group_rev: ActionHash,
+    /// Limit the copy to a single removed member
+    #[serde(default)]
+    pub agent: Option<AgentPubKey>,
+    /// The maximum number of links to copy in this call
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+
+/// Input for updating (or planning an update to) a group
 ///
-/// struct PostEntry {
-///     pub message: String,
-///     pub group_ref: GroupRef,
-/// }
-/// common_fields!( PostEntry, group_ref.id, group_ref.rev );
-/// ```
-#[macro_export]
-macro_rules! group_ref {
-    ( $type:ident, $($ref:tt).* ) => {
-        impl $crate::GroupRef for $type {
-            fn group_ref(&self) -> (ActionHash, ActionHash) {
-                self$(.$ref)*.to_owned()
-            }
-        }
-    };
-    ( $type:ident, $($id:tt).*, $($rev:tt).* ) => {
-        impl $crate::GroupRef for $type {
-            fn group_ref(&self) -> (ActionHash, ActionHash) {
-                (
-                    self$(.$id)*.to_owned(),
-                    self$(.$rev)*.to_owned()
-                )
-            }
-        }
-    };
+/// Wire-compatible with [`hdk_extensions::UpdateEntryInput<GroupEntry>`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateGroupInput {
+    pub base: ActionHash,
+    pub entry: GroupEntry,
+    /// Reject the update with [`CoopContentError::StaleRevision`] if `base` has already been
+    /// updated
+    #[serde(default)]
+    pub require_latest: bool,
+    /// An optional note explaining the change
+    #[serde(default)]
+    pub memo: Option<String>,
 }
 
 
 //
-// Validation helpers
+// CSR Output Structs
 //
-/// Checks that an entry's group reference and author are valid
-pub fn validate_group_auth<T>(
-    entry: &T,
-    action: impl Into<EntryCreationAction>
-) -> Result<(), String>
-where
-    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
-{
-    let creation_action : EntryCreationAction = action.into();
+/// The context and content of a specific entry revision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entity<T> {
+    /// The address of the original create action
+    pub id: ActionHash,
 
-    validate_group_ref( entry, creation_action.clone() )?;
-    validate_group_member( entry, creation_action )?;
+    /// The create/update action of the current entry
+    pub action: ActionHash,
 
-    Ok(())
+    /// The address of the current entry
+    pub address: EntryHash,
+
+    #[serde(rename = "type")]
+    /// An identifier for the content's type and structure
+    pub ctype: String,
+
+    /// The entity's current value
+    pub content: T,
+
+    /// The author of the current revision
+    pub author: AgentPubKey,
+
+    /// The time of the current revision's action
+    pub timestamp: Timestamp,
+
+    /// The position of the current revision in its history (the create is `0`)
+    pub revision: usize,
+
+    /// Whether the current revision has not been updated
+    pub is_latest: bool,
+
+    /// The revisions before the current one, oldest first (starting with `id`)
+    pub prior_revisions: Vec<ActionHash>,
+
+    /// Unmerged revision heads other than `action` when the history has forked
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forks: Vec<ActionHash>,
 }
 
+/// A single event in a group's activity feed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupActivity {
+    /// New content was registered to the group
+    ContentCreated {
+        timestamp: Timestamp,
+        author: AgentPubKey,
+        content_id: AnyLinkableHash,
+        content_type: String,
+        content_base: Option<String>,
+    },
+    /// A content update was registered to the group
+    ContentUpdated {
+        timestamp: Timestamp,
+        author: AgentPubKey,
+        content_id: AnyLinkableHash,
+        content_type: Option<String>,
+        content_prev: AnyLinkableHash,
+        content_next: AnyLinkableHash,
+    },
+    /// A group revision added a contributor
+    MemberAdded {
+        timestamp: Timestamp,
+        author: AgentPubKey,
+        group_rev: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// A group revision removed a contributor
+    MemberRemoved {
+        timestamp: Timestamp,
+        author: AgentPubKey,
+        group_rev: ActionHash,
+        agent: AgentPubKey,
+    },
+}
 
-/// Check that an entry's group reference is valid
-pub fn validate_group_ref<T>(
-    entry: &T,
-    action: impl Into<EntryCreationAction>
-) -> Result<(), String>
-where
-    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
-{
-    let group_ref = entry.group_ref();
+/// The error of a failed item in a batch call
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchError {
+    /// The full error message
+    pub message: String,
+    /// The decoded error when the failure was a [`CoopContentError`]
+    pub error: Option<CoopContentError>,
+}
 
-    if let EntryCreationAction::Update(update) = action.into() {
-        let prev_entry : T = must_get_entry( update.original_entry_address.to_owned() )?
-            .content.try_into()?;
-        let prev_group_ref = prev_entry.group_ref();
+impl From<WasmError> for BatchError {
+    fn from(error: WasmError) -> Self {
+        BatchError {
+            error: CoopContentError::try_from( &error ).ok(),
+            message: format!("{}", error ),
+        }
+    }
+}
 
-        if group_ref.0 != prev_group_ref.0 {
-            return Err("Content group ID cannot be changed".to_string())?;
+impl From<CoopContentError> for BatchError {
+    fn from(error: CoopContentError) -> Self {
+        BatchError {
+            message: error.to_message(),
+            error: Some(error),
         }
     }
+}
 
-    if group_ref.0 != trace_origin_root( &group_ref.1 )?.0 {
-        return Err("Content group ID is not the initial action for the group revision".to_string())?;
+/// The outcome of a single item in a batch call
+///
+/// Serializes as `{ "ok": <value> }` or `{ "err": { "message": <message>, "error": <decoded error
+/// or null> } }`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchResult<T> {
+    Ok(T),
+    Err(BatchError),
+}
+
+impl<T> BatchResult<T> {
+    /// Check if this item succeeded
+    pub fn is_ok(&self) -> bool {
+        matches!( self, BatchResult::Ok(_) )
     }
 
-    Ok(())
+    /// Get the success value, if any
+    pub fn ok(self) -> Option<T> {
+        match self {
+            BatchResult::Ok(value) => Some(value),
+            BatchResult::Err(_) => None,
+        }
+    }
 }
 
+impl<T> From<ExternResult<T>> for BatchResult<T> {
+    fn from(result: ExternResult<T>) -> Self {
+        match result {
+            Ok(value) => BatchResult::Ok(value),
+            Err(err) => BatchResult::Err(err.into()),
+        }
+    }
+}
 
-/// Checks that the author of an action is an authority in the entry's group reference
-pub fn validate_group_member<T>(
-    entry: &T,
-    action: impl Into<EntryCreationAction>
-) -> Result<(), String>
-where
-    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
-{
-    let creation_action : EntryCreationAction = action.into();
-    let author = creation_action.author();
+/// How far the archive copy has progressed for a removed member
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArchiveProgress {
+    /// The removed member
+    pub agent: AgentPubKey,
+    /// The member's archive anchor for the removing group revision
+    pub anchor: EntryHash,
+    /// The number of contribution (create and update) links to copy
+    pub total: usize,
+    /// The number of links already copied
+    pub copied: usize,
+}
 
-    let group_ref = entry.group_ref();
-    let signed_action = must_get_action( group_ref.1.to_owned() )?;
-    let group : GroupEntry = match signed_action.action().entry_hash() {
-        Some(entry_addr) => must_get_entry( entry_addr.to_owned() )?
-            .content.try_into()?,
-        None => return Err(format!("Action ({}) does not contain an entry hash", group_ref.1 )),
-    };
+impl ArchiveProgress {
+    /// Check if every link has been copied
+    pub fn is_complete(&self) -> bool {
+        self.copied >= self.total
+    }
+}
+
+/// A link (or set of links) that a group update will create
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupUpdateStep {
+    /// Carry a `GroupAuthArchive` link from the base revision to the new revision
+    CarryArchive {
+        anchor: EntryHash,
+    },
+    /// Link a new archive anchor for a removed member and copy their contribution links to it
+    ArchiveMember {
+        agent: AgentPubKey,
+        /// The number of contribution (create and update) links to copy
+        links: usize,
+    },
+    /// Link an added member's contributions anchor and invite them to the group
+    AddMember {
+        agent: AgentPubKey,
+        anchor: EntryHash,
+    },
+    /// Link an unchanged member's contributions anchor
+    KeepMember {
+        agent: AgentPubKey,
+        anchor: EntryHash,
+    },
+}
+
+/// The result of planning a group update without committing it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupUpdatePlan {
+    pub group_id: ActionHash,
+    pub base: ActionHash,
+    /// The latest known revision following `base`
+    pub latest: ActionHash,
+    pub contributors_diff: ContributorsDiff,
+    pub steps: Vec<GroupUpdateStep>,
+}
+
+impl GroupUpdatePlan {
+    /// Check if the base revision has already been updated
+    pub fn is_stale(&self) -> bool {
+        self.base != self.latest
+    }
+}
+
+/// Structural problems found in a single group revision
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupRevisionAudit {
+    pub group_rev: ActionHash,
+    /// Contributors without a `GroupAuth` link to their contributions anchor
+    pub missing_auth_links: Vec<AgentPubKey>,
+    /// Expected archive anchors (removed or carried over) without a `GroupAuthArchive` link
+    pub missing_archive_links: Vec<EntryHash>,
+    /// Archive anchors of removed members that are missing copied links
+    pub incomplete_archives: Vec<ArchiveProgress>,
+    /// Anchors linked from this revision that do not belong to it
+    pub orphaned_anchors: Vec<EntryHash>,
+}
+
+impl GroupRevisionAudit {
+    /// Check if no problems were found
+    pub fn is_healthy(&self) -> bool {
+        self.missing_auth_links.is_empty()
+            && self.missing_archive_links.is_empty()
+            && self.incomplete_archives.is_empty()
+            && self.orphaned_anchors.is_empty()
+    }
+}
+
+/// The audit results for every revision of a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupAudit {
+    pub group_id: ActionHash,
+    pub revisions: Vec<GroupRevisionAudit>,
+}
+
+impl GroupAudit {
+    /// Check if no problems were found in any revision
+    pub fn is_healthy(&self) -> bool {
+        self.revisions.iter().all( |revision| revision.is_healthy() )
+    }
+}
+
+/// Content counts for a single contributions anchor
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContributorStats {
+    pub agent: AgentPubKey,
+    /// The active or archive contributions anchor that was counted
+    pub anchor: EntryHash,
+    /// The number of created content IDs per content type
+    pub content_types: BTreeMap<String, usize>,
+    /// The number of content updates
+    pub updates: usize,
+    /// The time of the latest contribution link
+    pub last_activity: Option<Timestamp>,
+}
+
+/// Summary counts for the latest state of a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupStats {
+    pub group_id: ActionHash,
+    /// The number of revisions in the group's evolution
+    pub revisions: usize,
+    /// The number of unique content IDs per content type
+    pub content_types: BTreeMap<String, usize>,
+    /// Stats for each current contributor
+    pub contributors: Vec<ContributorStats>,
+    /// Stats for each archived (removed) member
+    pub archived: Vec<ContributorStats>,
+    /// The time of the latest group revision or contribution link
+    pub last_activity: Timestamp,
+}
+
+/// Why a link or update was accepted or rejected during content resolution
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionReason {
+    /// Accepted because it came from a current contributor
+    Contributor,
+    /// Accepted because it came from an archived (removed) member's anchor
+    ArchivedContributor,
+    /// Accepted because an archive anchor lists the update as an exception
+    ArchivedException,
+    /// Rejected because the author is not a contributor and no archive lists the update
+    NonContributor,
+    /// Rejected because it was created after the `as_of` cutoff
+    AfterCutoff,
+    /// Rejected because an earlier valid update of the same revision was followed
+    NotEarliest,
+    /// Rejected because another update link from the same revision was used
+    Superseded,
+    /// Rejected because the link's previous revision is not part of the followed evolutions
+    Unreachable,
+    /// Rejected because the link target is not an action
+    NonActionTarget,
+}
+
+/// A single decision made while resolving a content's evolutions
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResolutionStep {
+    /// A contribution (create) link for the content
+    ContributionLink {
+        anchor: EntryHash,
+        target: AnyLinkableHash,
+        accepted: bool,
+        reason: ResolutionReason,
+    },
+    /// A contribution update link for the content
+    UpdateLink {
+        anchor: EntryHash,
+        content_prev: AnyLinkableHash,
+        content_next: AnyLinkableHash,
+        accepted: bool,
+        reason: ResolutionReason,
+    },
+    /// An update action found while tracing the content's evolutions (full trace only)
+    Update {
+        content_prev: ActionHash,
+        content_next: ActionHash,
+        author: AgentPubKey,
+        accepted: bool,
+        reason: ResolutionReason,
+    },
+}
+
+/// A content's resolved evolutions and the steps that produced them
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentResolution {
+    pub content_id: AnyLinkableHash,
+    /// The group revision used for authority
+    pub group_rev: ActionHash,
+    pub full_trace: bool,
+    pub evolutions: Vec<AnyLinkableHash>,
+    pub steps: Vec<ResolutionStep>,
+}
+
+/// A content whose latest revision differs between the shortcut and full-trace methods
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentDivergence {
+    pub content_id: AnyLinkableHash,
+    /// The latest revision using shortcuts (`None` if the content was not found)
+    pub shortcuts: Option<AnyLinkableHash>,
+    /// The latest revision using full trace (`None` if the content was not found)
+    pub full_trace: Option<AnyLinkableHash>,
+}
+
+/// A single group revision and what changed from its previous revision
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupRevisionHistory {
+    pub group_rev: ActionHash,
+    /// The revision that this one updated (`None` for the group's create)
+    pub previous: Option<ActionHash>,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+    pub contributors_diff: ContributorsDiff,
+    pub metadata_changes: Vec<MetadataChange>,
+    /// The note left by the revision's author
+    pub memo: Option<String>,
+}
+
+/// A content item and its descendants in a content tree
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentTreeNode {
+    /// The content ID
+    pub id: AnyLinkableHash,
+    /// The latest revision of the content
+    pub latest: AnyLinkableHash,
+    pub children: Vec<ContentTreeNode>,
+    /// Whether some children were left out because of the depth or breadth limits
+    pub truncated: bool,
+}
+
+/// A content type of a group and the number of content IDs using it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupContentType {
+    pub name: String,
+    pub schema: Option<String>,
+    /// Whether the latest group revision declares this type
+    pub declared: bool,
+    /// The number of unique content IDs of this type
+    pub count: usize,
+}
+
+/// A pending request from an agent to join a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupJoinRequest {
+    /// The create link action of the request
+    pub id: ActionHash,
+    pub group_id: ActionHash,
+    pub agent: AgentPubKey,
+    pub message: Option<String>,
+    pub timestamp: Timestamp,
+}
+
+/// An unexpired lock on a content ID within a group
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentLock {
+    /// The create link action of the lock
+    pub id: ActionHash,
+    pub group_id: ActionHash,
+    pub content_id: AnyLinkableHash,
+    pub holder: AgentPubKey,
+    /// The group revision where the holder is a contributor
+    pub group_rev: ActionHash,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+/// A proposed content update waiting for review
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentProposal {
+    /// The create link action of the proposal
+    pub id: ActionHash,
+    pub group_id: ActionHash,
+    pub content_id: AnyLinkableHash,
+    pub content_prev: AnyLinkableHash,
+    pub content_next: AnyLinkableHash,
+    pub author: AgentPubKey,
+    /// The group revision where the author is a contributor
+    pub group_rev: ActionHash,
+    pub timestamp: Timestamp,
+}
+
+/// A governance proposal with its current tally
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GovernanceProposal {
+    /// The create action of the proposal entry
+    pub id: ActionHash,
+    pub group_id: ActionHash,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+    pub proposal: GovernanceProposalEntry,
+    /// Contributors of the proposal's group revision who voted to approve
+    pub approvals: Vec<AgentPubKey>,
+    /// Contributors of the proposal's group revision who voted to reject
+    pub rejections: Vec<AgentPubKey>,
+    /// The number of approvals needed to commit
+    pub quorum: usize,
+    /// The group revision that committed this proposal
+    pub committed: Option<ActionHash>,
+    /// Whether the proposal's group revision has been updated without committing it
+    pub stale: bool,
+}
+
+/// The content targets from a contributor's links for a single content type
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ContributionTargets {
+    /// Targets of contribution links (content IDs)
+    pub creates: Vec<AnyLinkableHash>,
+    /// Targets of contribution update links (content revisions)
+    pub updates: Vec<AnyLinkableHash>,
+}
+
+/// An agent's contributions to a single group grouped by content type
+///
+/// Updates to content whose type cannot be determined are grouped under an empty content type.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AgentContributions {
+    pub group_id: ActionHash,
+    pub content_types: BTreeMap<String, ContributionTargets>,
+}
+
+impl GroupActivity {
+    /// Get the time that this event happened
+    pub fn timestamp(&self) -> &Timestamp {
+        match self {
+            GroupActivity::ContentCreated { timestamp, .. } => timestamp,
+            GroupActivity::ContentUpdated { timestamp, .. } => timestamp,
+            GroupActivity::MemberAdded { timestamp, .. } => timestamp,
+            GroupActivity::MemberRemoved { timestamp, .. } => timestamp,
+        }
+    }
+
+    /// Get the agent that caused this event
+    pub fn author(&self) -> &AgentPubKey {
+        match self {
+            GroupActivity::ContentCreated { author, .. } => author,
+            GroupActivity::ContentUpdated { author, .. } => author,
+            GroupActivity::MemberAdded { author, .. } => author,
+            GroupActivity::MemberRemoved { author, .. } => author,
+        }
+    }
+
+    /// Get the content type of a content event
+    pub fn content_type(&self) -> Option<&String> {
+        match self {
+            GroupActivity::ContentCreated { content_type, .. } => Some(content_type),
+            GroupActivity::ContentUpdated { content_type, .. } => content_type.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+
+
+//
+// Signals
+//
+/// A signal emitted (locally and to affected contributors) when group state changes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoopContentSignal {
+    /// A group was created
+    GroupCreated {
+        group_id: ActionHash,
+        author: AgentPubKey,
+    },
+    /// A group was updated
+    GroupUpdated {
+        group_id: ActionHash,
+        group_rev: ActionHash,
+        prev_rev: ActionHash,
+        author: AgentPubKey,
+    },
+    /// An agent was invited to a group
+    GroupInvite {
+        group_id: ActionHash,
+        agent: AgentPubKey,
+        author: AgentPubKey,
+    },
+    /// A contribution link was created
+    ContributionCreated {
+        group_id: ActionHash,
+        author: AgentPubKey,
+        content_id: AnyLinkableHash,
+        content_type: Option<String>,
+        content_base: Option<String>,
+    },
+    /// A contribution update link was created
+    ContributionUpdated {
+        group_id: ActionHash,
+        author: AgentPubKey,
+        content_id: AnyLinkableHash,
+        content_prev: AnyLinkableHash,
+        content_next: AnyLinkableHash,
+    },
+}
+
+impl CoopContentSignal {
+    /// Get the group that this signal is about
+    pub fn group_id(&self) -> &ActionHash {
+        match self {
+            CoopContentSignal::GroupCreated { group_id, .. } => group_id,
+            CoopContentSignal::GroupUpdated { group_id, .. } => group_id,
+            CoopContentSignal::GroupInvite { group_id, .. } => group_id,
+            CoopContentSignal::ContributionCreated { group_id, .. } => group_id,
+            CoopContentSignal::ContributionUpdated { group_id, .. } => group_id,
+        }
+    }
+
+    /// Get the agent who made the change that this signal is about
+    pub fn author(&self) -> &AgentPubKey {
+        match self {
+            CoopContentSignal::GroupCreated { author, .. } => author,
+            CoopContentSignal::GroupUpdated { author, .. } => author,
+            CoopContentSignal::GroupInvite { author, .. } => author,
+            CoopContentSignal::ContributionCreated { author, .. } => author,
+            CoopContentSignal::ContributionUpdated { author, .. } => author,
+        }
+    }
+}
+
+
+
+//
+// A trait for determining a group state
+//
+/// A trait for determining an entry's group reference
+pub trait GroupRef {
+    fn group_ref(&self) -> (ActionHash, ActionHash);
+}
+
+impl GroupRef for (ActionHash, ActionHash) {
+    fn group_ref(&self) -> (ActionHash, ActionHash) {
+        self.to_owned()
+    }
+}
+
+/// Easily-implement the [`GroupRef`] trait
+///
+/// When using a single field, the 2 [`ActionHash`] tuple order must be `(ID, revision)`
+///
+/// #### Examples
+///
+/// ##### Example: Single Field
+/// ```ignore
+/// struct PostEntry {
+///     pub message: String,
+///     pub group_ref: (ActionHash, ActionHash),
+/// }
+/// common_fields!( PostEntry, group_ref );
+/// ```
+///
+/// ##### Example: Separate Fields
+/// ```ignore
+/// struct PostEntry {
+///     pub message: String,
+///
+///     pub group_id: ActionHash,
+///     pub group_rev: ActionHash,
+/// }
+/// common_fields!( PostEntry, group_id, group_rev );
+/// ```
+///
+/// ##### Example: Separate Struct
+/// ```ignore
+/// struct GroupRef {
+///     pub id: ActionHash,
+///     pub rev: ActionHash,
+/// }
+///
+/// struct PostEntry {
+///     pub message: String,
+///     pub group_ref: GroupRef,
+/// }
+/// common_fields!( PostEntry, group_ref.id, group_ref.rev );
+/// ```
+#[macro_export]
+macro_rules! group_ref {
+    ( $type:ident, $($ref:tt).* ) => {
+        impl $crate::GroupRef for $type {
+            fn group_ref(&self) -> (ActionHash, ActionHash) {
+                self$(.$ref)*.to_owned()
+            }
+        }
+    };
+    ( $type:ident, $($id:tt).*, $($rev:tt).* ) => {
+        impl $crate::GroupRef for $type {
+            fn group_ref(&self) -> (ActionHash, ActionHash) {
+                (
+                    self$(.$id)*.to_owned(),
+                    self$(.$rev)*.to_owned()
+                )
+            }
+        }
+    };
+}
+
+
+//
+// Validation helpers
+//
+/// Checks that an entry's group reference and author are valid
+pub fn validate_group_auth<T>(
+    entry: &T,
+    action: impl Into<EntryCreationAction>
+) -> Result<(), String>
+where
+    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
+{
+    let creation_action : EntryCreationAction = action.into();
+
+    validate_group_ref( entry, creation_action.clone() )?;
+    validate_group_member( entry, creation_action )?;
+
+    Ok(())
+}
+
+
+/// Check that an entry's group reference is valid
+pub fn validate_group_ref<T>(
+    entry: &T,
+    action: impl Into<EntryCreationAction>
+) -> Result<(), String>
+where
+    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
+{
+    let group_ref = entry.group_ref();
+
+    validate_group_id_unchanged( entry, action )?;
+
+    if group_ref.0 != trace_origin_root( &group_ref.1 )?.0 {
+        Err("Content group ID is not the initial action for the group revision".to_string())?;
+    }
+
+    Ok(())
+}
+
+
+/// Check that an update does not change the entry's group ID
+fn validate_group_id_unchanged<T>(
+    entry: &T,
+    action: impl Into<EntryCreationAction>
+) -> Result<(), String>
+where
+    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
+{
+    if let EntryCreationAction::Update(update) = action.into() {
+        let prev_entry : T = must_get_entry( update.original_entry_address.to_owned() )?
+            .content.try_into()?;
+
+        if entry.group_ref().0 != prev_entry.group_ref().0 {
+            Err("Content group ID cannot be changed".to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Checks that the author of an action is an authority in the entry's group reference
+pub fn validate_group_member<T>(
+    entry: &T,
+    action: impl Into<EntryCreationAction>
+) -> Result<(), String>
+where
+    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
+{
+    let creation_action : EntryCreationAction = action.into();
+    let author = creation_action.author();
+
+    let group_ref = entry.group_ref();
+    let signed_action = must_get_action( group_ref.1.to_owned() )?;
+    let group : GroupEntry = match signed_action.action().entry_hash() {
+        Some(entry_addr) => must_get_entry( entry_addr.to_owned() )?
+            .content.try_into()?,
+        None => return Err(format!("Action ({}) does not contain an entry hash", group_ref.1 )),
+    };
+
+    if !group.is_contributor( author ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: group_ref.0,
+            agent: author.to_owned(),
+        })?;
+    }
+
+    Ok(())
+}
+
+
+/// The group revision records from a group ID to one of its revisions
+///
+/// Used as evidence of group membership when validating content in a DNA that cannot get the group
+/// records (eg. when the coop content zomes run in a separate "groups" DNA).  Get it from the
+/// `get_group_membership_proof` function (see [`get_group_membership_proof`]) and store it with
+/// the content so that it is available to validation.
+///
+/// Like [`validate_group_member`], a proof shows that the revision was authorized; it does not
+/// show that the revision is the group's latest state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupMembershipProof {
+    /// The group's create record followed by each update leading to the revision
+    pub revisions: Vec<Record>,
+}
+
+impl GroupMembershipProof {
+    /// Verify that the records are a signed, authorized chain of group revisions from `group_id`
+    /// to `group_rev` and return the group state at `group_rev`
+    ///
+    /// Each revision must be authored by an admin of the revision before it (or a member removing
+    /// themselves), the same as the integrity rules for group entries.
+    pub fn verify(&self, group_id: &ActionHash, group_rev: &ActionHash) -> Result<GroupEntry, String> {
+        let mut previous : Option<(&ActionHash, GroupEntry)> = None;
+
+        for record in self.revisions.iter() {
+            let action = record.action();
+            let action_addr = record.action_address();
+
+            if hash_action( action.to_owned() )? != *action_addr {
+                Err(format!("Membership proof record '{}' does not match its action", action_addr ))?;
+            }
+
+            if !verify_signature( action.author().to_owned(), record.signature().to_owned(), action )? {
+                Err(format!("Membership proof record '{}' has an invalid signature", action_addr ))?;
+            }
+
+            let entry = record.entry().as_option()
+                .ok_or(format!("Membership proof record '{}' does not have an entry", action_addr ))?;
+
+            if Some(&hash_entry( entry.to_owned() )?) != action.entry_hash() {
+                Err(format!("Membership proof record '{}' does not match its entry", action_addr ))?;
+            }
+
+            let group = GroupEntry::try_from( entry.to_owned() )?;
+
+            match (&previous, action) {
+                (None, Action::Create(_)) => {
+                    if action_addr != group_id {
+                        Err(CoopContentError::NotARevision {
+                            id: group_id.to_owned(),
+                            address: group_rev.to_owned(),
+                        })?;
+                    }
+
+                    if !group.is_admin( action.author() ) {
+                        Err(format!("Membership proof group '{}' was not created by an admin", group_id ))?;
+                    }
+                },
+                (Some((prev_addr, prev_group)), Action::Update(update)) => {
+                    if update.original_action_address != **prev_addr {
+                        Err(CoopContentError::NotARevision {
+                            id: (*prev_addr).to_owned(),
+                            address: action_addr.to_owned(),
+                        })?;
+                    }
+
+                    if !prev_group.is_admin( action.author() )
+                        && !prev_group.is_self_removal( &group, action.author() ) {
+                        Err(format!("Membership proof revision '{}' was not made by an admin", action_addr ))?;
+                    }
+                },
+                _ => Err("Membership proof must be a group create followed by its updates".to_string())?,
+            }
+
+            previous = Some(( action_addr, group ));
+        }
+
+        match previous {
+            Some((addr, group)) if addr == group_rev => Ok( group ),
+            _ => Err(CoopContentError::NotARevision {
+                id: group_id.to_owned(),
+                address: group_rev.to_owned(),
+            })?,
+        }
+    }
+}
+
+
+/// Checks that an entry's group reference and author are valid using a membership proof instead
+/// of getting the group records
+///
+/// Use this instead of [`validate_group_auth`] when the coop content zomes are in a different DNA
+/// than the content.
+pub fn validate_group_auth_with_proof<T>(
+    entry: &T,
+    action: impl Into<EntryCreationAction>,
+    proof: &GroupMembershipProof,
+) -> Result<(), String>
+where
+    T: GroupRef + TryFrom<Entry, Error = WasmError> + Clone,
+{
+    let creation_action : EntryCreationAction = action.into();
+    let author = creation_action.author().to_owned();
+    let group_ref = entry.group_ref();
+
+    validate_group_id_unchanged( entry, creation_action )?;
+
+    let group = proof.verify( &group_ref.0, &group_ref.1 )?;
+
+    if !group.is_contributor( &author ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: group_ref.0,
+            agent: author,
+        })?;
+    }
+
+    Ok(())
+}
+
+
+//
+// Zome call helpers
+//
+/// The cell and capability secret used by the zome call macros
+///
+/// The SDK macros call the local cell unless they are prefixed with `target = <target>;` where the
+/// target is anything that converts into this struct (eg. a [`CallTargetCell`]).  This allows the
+/// coop content zomes to run in a separate DNA (eg. a "groups" role) from the app's content.
+///
+/// ##### Example: Other Role
+/// ```
+/// # use coop_content_sdk::*;
+/// # use coop_content_sdk::hdk::prelude::*;
+/// let target = ZomeCallTarget::role("groups");
+/// let target_with_secret = ZomeCallTarget::from( CallTargetCell::OtherRole("groups".into()) )
+///     .with_cap_secret( CapSecret::from([0; 64]) );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ZomeCallTarget {
+    pub cell: CallTargetCell,
+    pub cap_secret: Option<CapSecret>,
+}
+
+impl ZomeCallTarget {
+    /// Target the cell making the call
+    pub fn local() -> Self {
+        CallTargetCell::Local.into()
+    }
+
+    /// Target another cell of the same app by its role name
+    pub fn role(role_name: impl Into<RoleName>) -> Self {
+        CallTargetCell::OtherRole( role_name.into() ).into()
+    }
+
+    /// Use the given capability secret for the call
+    pub fn with_cap_secret(mut self, cap_secret: CapSecret) -> Self {
+        self.cap_secret = Some(cap_secret);
+        self
+    }
+}
+
+impl Default for ZomeCallTarget {
+    fn default() -> Self {
+        ZomeCallTarget::local()
+    }
+}
+
+impl From<CallTargetCell> for ZomeCallTarget {
+    fn from(cell: CallTargetCell) -> Self {
+        ZomeCallTarget {
+            cell,
+            cap_secret: None,
+        }
+    }
+}
+
+
+/// Call a zome function in the given target cell
+///
+/// The target can be anything that converts into a [`ZomeCallTarget`].
+///
+/// A [`CoopContentError`] returned by the called function is re-raised as its own guest error
+/// (instead of the host error that wraps it) so that it can be decoded using
+/// `CoopContentError::try_from( &err )`.
+///
+/// ##### Example: Basic Usage
+/// ```
+/// # use coop_content_sdk::*;
+/// # use coop_content_sdk::hdk::prelude::*;
+/// fn example() -> ExternResult<()> {
+///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
+///
+///     call_zome!(
+///         ZomeCallTarget::role("groups"),
+///         "coop_content_csr",
+///         "get_group",
+///         ActionHash::try_from(group_id).unwrap()
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! call_zome {
+    ( $target:expr, $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        {
+            use $crate::hdk;
+            use $crate::hdi_extensions::guest_error;
+
+            let target : $crate::ZomeCallTarget = ($target).into();
+
+            match hdk::prelude::call(
+                target.cell,
+                $zome,
+                $fn.into(),
+                target.cap_secret,
+                $($input)+,
+            ).map_err( |err| match $crate::CoopContentError::try_from( &err ) {
+                Ok(error) => hdk::prelude::WasmError::from( error ),
+                Err(_) => err,
+            })? {
+                ZomeCallResponse::Ok(extern_io) => Ok(extern_io),
+                ZomeCallResponse::NetworkError(msg) => Err(guest_error!(format!("{}", msg))),
+                ZomeCallResponse::CountersigningSession(msg) => Err(guest_error!(format!("{}", msg))),
+                _ => Err(guest_error!(format!("Zome call response: Unauthorized"))),
+            }
+        }
+    };
+}
+
+/// Call a zome function in the given target cell and decode the response
+///
+/// ##### Example: Basic Usage
+/// ```
+/// # use coop_content_sdk::*;
+/// # use coop_content_sdk::hdk::prelude::*;
+/// fn example() -> ExternResult<()> {
+///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
+///
+///     let group : Entity<GroupEntry> = call_zome_decode!(
+///         CallTargetCell::OtherRole("groups".into()),
+///         "coop_content_csr",
+///         "get_group",
+///         ActionHash::try_from(group_id).unwrap()
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! call_zome_decode {
+    ( $target:expr, $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        $crate::call_zome!( $target, $zome, $fn, $($input)+ )?
+            .decode()
+            .map_err(|err| $crate::hdk::prelude::wasm_error!($crate::hdk::prelude::WasmErrorInner::from(err)) )
+    };
+    ( $target:expr, $into_type:ident, $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        $crate::call_zome!( $target, $zome, $fn, $($input)+ )?
+            .decode::<$into_type>()
+            .map_err(|err| $crate::hdk::prelude::wasm_error!($crate::hdk::prelude::WasmErrorInner::from(err)) )
+    };
+}
+
+/// Call a local zome function
+///
+/// Same as [`call_zome`] with [`ZomeCallTarget::local`].
+///
+/// ##### Example: Basic Usage
+/// ```
+/// # use coop_content_sdk::*;
+/// # use coop_content_sdk::hdk::prelude::*;
+/// fn example() -> ExternResult<()> {
+///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
+///     let content_addr = "uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0";
+///
+///     call_local_zome!(
+///         "coop_content_csr",
+///         "create_content_link",
+///         coop_content_sdk::CreateContributionLinkInput {
+///             group_id: ActionHash::try_from(group_id).unwrap(),
+///             content_target: ActionHash::try_from(content_addr).unwrap().into(),
+///             content_type: String::from("post"),
+///             content_base: None,
+///         }
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! call_local_zome {
+    ( $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        $crate::call_zome!( $crate::ZomeCallTarget::local(), $zome, $fn, $($input)+ )
+    };
+}
+
+/// Call a local zome function and decode the response
+///
+/// ##### Example: Basic Usage
+/// ```
+/// # use coop_content_sdk::*;
+/// # use coop_content_sdk::hdk::prelude::*;
+/// fn example() -> ExternResult<()> {
+///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
+///     let content_addr = "uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0";
+///
+///     call_local_zome_decode!(
+///         ActionHash,
+///         "coop_content_csr",
+///         "create_content_link",
+///         coop_content_sdk::CreateContributionLinkInput {
+///             group_id: ActionHash::try_from(group_id).unwrap(),
+///             content_target: ActionHash::try_from(content_addr).unwrap().into(),
+///             content_type: String::from("post"),
+///             content_base: None,
+///         }
+///     )?;
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[macro_export]
+macro_rules! call_local_zome_decode {
+    ( $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        $crate::call_zome_decode!( $crate::ZomeCallTarget::local(), $zome, $fn, $($input)+ )
+    };
+    ( $into_type:ident, $zome:literal, $fn:literal, $($input:tt)+ ) => {
+        $crate::call_zome_decode!( $crate::ZomeCallTarget::local(), $into_type, $zome, $fn, $($input)+ )
+    };
+}
+
+
+/// Input required for macros [`register_content_to_group`]
+#[derive(Clone)]
+pub struct RegisterContributionMacroInput<T>
+where
+    T: GroupRef + Clone,
+{
+    /// The content entry belonging to the target
+    pub entry: T,
+    /// An entry creation action address
+    pub target: ActionHash,
+    /// The type name of the target content
+    pub content_type: String,
+    /// Optional base filter of the target content
+    pub content_base: Option<String>,
+}
+
+
+/// Register a new content target to a group
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <template>`
+/// - #2 - `<zome name>, <template>`
+/// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The input template is [`RegisterContributionMacroInput`].
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `create_content_link`
+///
+/// Returns [`ActionHash`]
+///
+/// #### Examples
+/// All examples assume this setup
+/// ```ignore
+/// #[hdk_entry_helper]
+/// struct PostEntry {
+///     pub message: String,
+/// }
+///
+/// #[hdk_entry_defs]
+/// #[unit_enum(EntryTypesUnit)]
+/// pub enum EntryTypes {
+///     #[entry_def]
+///     Post(PostEntry),
+/// }
+///
+/// let post = PostEntry {
+///     message: "Hello world".to_string(),
+/// };
+/// let create_addr = create_entry( EntryTypes::Post(post) )?;
+/// ```
+///
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let link_addr = register_content_to_group!({
+///     entry: post,
+///     target: create_addr,
+/// })?;
+/// ```
+///
+/// ##### Example: Custom Zome Name
+/// ```ignore
+/// let link_addr = register_content_to_group!(
+///     "coop_content_csr_renamed",
+///     {
+///         entry: post,
+///         target: create_addr,
+///     }
+/// )?;
+/// ```
+///
+/// ##### Example: Custom Zome and Function Names
+/// ```ignore
+/// let link_addr = register_content_to_group!(
+///     "custom_coop_content_csr",
+///     "register_content_link",
+///     {
+///         entry: post,
+///         target: create_addr,
+///     }
+/// )?;
+/// ```
+///
+/// ##### Example: Other Role
+/// ```ignore
+/// let link_addr = register_content_to_group!(
+///     target = ZomeCallTarget::role("groups");
+///     {
+///         entry: post,
+///         target: create_addr,
+///     }
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! register_content_to_group {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+        {
+            use $crate::GroupRef;
+            let input = $crate::RegisterContributionMacroInput $($def)*;
+
+            $crate::call_zome_decode!(
+                $target,
+                ActionHash,
+                $zome,
+                $fn_name,
+                $crate::CreateContributionLinkInput {
+                    group_id: input.entry.group_ref().0,
+                    content_target: input.target.clone().into(),
+                    content_type: input.content_type,
+                    content_base: input.content_base,
+                }
+            )
+        }
+    };
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::register_content_to_group!( target = $target; $zome, "create_content_link", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::register_content_to_group!( target = $target; "coop_content_csr", $($def)* )
+    };
+    ( $($def:tt)* ) => {
+        $crate::register_content_to_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
+}
+
+
+/// Input required for macros [`register_content_update_to_group`]
+///
+/// `respect_locks` can be left out of the macro template.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RegisterContributionUpdateMacroInput<T>
+where
+    T: GroupRef + Clone,
+{
+    /// The content entry belonging to the target
+    pub entry: T,
+    /// An entry creation action address
+    pub target: ActionHash,
+    /// Refuse the update if another agent holds a lock on the content (defaults to `false`)
+    #[serde(default)]
+    pub respect_locks: bool,
+}
+
+
+/// Build a [`RegisterContributionUpdateMacroInput`] from a template, defaulting `respect_locks` to
+/// `false` when it is left out
+#[doc(hidden)]
+#[macro_export]
+macro_rules! content_update_macro_input {
+    ( { $($fields:tt)* } ) => {
+        $crate::content_update_macro_input!( @munch []; false; $($fields)* )
+    };
+    ( @munch [$($given:tt)*]; $locks:expr; respect_locks : $value:expr $(, $($rest:tt)*)? ) => {
+        $crate::content_update_macro_input!( @munch [$($given)*]; $value; $($($rest)*)? )
+    };
+    ( @munch [$($given:tt)*]; $locks:expr; $field:ident : $value:expr $(, $($rest:tt)*)? ) => {
+        $crate::content_update_macro_input!( @munch [$($given)* $field: $value,]; $locks; $($($rest)*)? )
+    };
+    ( @munch [$($given:tt)*]; $locks:expr; ) => {
+        $crate::RegisterContributionUpdateMacroInput {
+            $($given)*
+            respect_locks: $locks,
+        }
+    };
+}
+
+
+/// Register a content update target to a group
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <template>`
+/// - #2 - `<zome name>, <template>`
+/// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The input template is [`RegisterContributionUpdateMacroInput`].
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `create_content_update_link`
+///
+/// Returns [`ActionHash`]
+///
+/// #### Examples
+/// All examples assume this setup
+/// ```ignore
+/// #[hdk_entry_helper]
+/// struct PostEntry {
+///     pub message: String,
+/// }
+///
+/// #[hdk_entry_defs]
+/// #[unit_enum(EntryTypesUnit)]
+/// pub enum EntryTypes {
+///     #[entry_def]
+///     Post(PostEntry),
+/// }
+///
+/// let post = PostEntry {
+///     message: "Hello world".to_string(),
+/// };
+/// let create_addr = create_entry( EntryTypes::Post(post) )?;
+///
+/// let post_updated = PostEntry {
+///     message: "Hello world (updated)".to_string(),
+/// };
+/// let update_addr = update_entry( create_addr, EntryTypes::Post(post_updated) )?;
+/// ```
+///
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let link_addr = register_content_update_to_group!({
+///     entry: post_updated,
+///     target: update_addr,
+/// })?;
+/// ```
+///
+/// ##### Example: Refuse Locked Content
+/// Returns [`CoopContentError::ContentLocked`] if another agent holds a lock on the post
+/// ```ignore
+/// let link_addr = register_content_update_to_group!({
+///     entry: post_updated,
+///     target: update_addr,
+///     respect_locks: true,
+/// })?;
+/// ```
+///
+/// ##### Example: Custom Zome Name
+/// ```ignore
+/// let link_addr = register_content_update_to_group!(
+///     "coop_content_csr_renamed",
+///     {
+///         entry: post_updated,
+///         target: update_addr,
+///     }
+/// )?;
+/// ```
+///
+/// ##### Example: Custom Zome and Function Names
+/// ```ignore
+/// let link_addr = register_content_update_to_group!(
+///     "custom_coop_content_csr",
+///     "register_content_update_link",
+///     {
+///         entry: post_updated,
+///         target: update_addr,
+///     }
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! register_content_update_to_group {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+        {
+            use $crate::hdi_extensions::trace_origin;
+            use $crate::GroupRef;
+
+            let input = $crate::content_update_macro_input!( $($def)* );
+            let history = trace_origin( &input.target )?;
+
+            if history.len() < 2 {
+                Err($crate::CoopContentError::NotAnUpdate {
+                    address: input.target.to_owned(),
+                })?
+            }
+
+            let content_id = &history[ history.len() - 1 ].0;
+            let content_prev_rev = &history[1].0;
+
+            $crate::call_zome_decode!(
+                $target,
+                ActionHash,
+                $zome,
+                $fn_name,
+                $crate::CreateContributionUpdateLinkInput {
+                    group_id: input.entry.group_ref().0,
+                    content_id: content_id.clone().into(),
+                    content_prev: content_prev_rev.clone().into(),
+                    content_next: input.target.clone().into(),
+                    respect_locks: input.respect_locks,
+                }
+            )
+        }
+    };
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::register_content_update_to_group!( target = $target; $zome, "create_content_update_link", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::register_content_update_to_group!( target = $target; "coop_content_csr", $($def)* )
+    };
+    ( $($def:tt)* ) => {
+        $crate::register_content_update_to_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
+}
+
+
+/// Register many new content targets to a group
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <inputs>`
+/// - #2 - `<zome name>, <inputs>`
+/// - #3 - `<inputs>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The inputs are an iterable of [`RegisterContributionMacroInput`].
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `create_content_links`
+///
+/// Returns [`Vec<BatchResult<ActionHash>>`] in the same order as the inputs
+///
+/// #### Examples
+/// All examples assume the setup from [`register_content_to_group`] with a list of created posts
+/// ```ignore
+/// let posts : Vec<(PostEntry, ActionHash)> = ...;
+/// let inputs : Vec<RegisterContributionMacroInput<PostEntry>> = posts.into_iter()
+///     .map( |(post, create_addr)| RegisterContributionMacroInput {
+///         entry: post,
+///         target: create_addr,
+///         content_type: "post".to_string(),
+///         content_base: None,
+///     })
+///     .collect();
+/// ```
+///
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let results = register_contents_to_group!( inputs )?;
+/// ```
+///
+/// ##### Example: Custom Zome Name
+/// ```ignore
+/// let results = register_contents_to_group!(
+///     "coop_content_csr_renamed",
+///     inputs
+/// )?;
+/// ```
+///
+/// ##### Example: Custom Zome and Function Names
+/// ```ignore
+/// let results = register_contents_to_group!(
+///     "custom_coop_content_csr",
+///     "register_content_links",
+///     inputs
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! register_contents_to_group {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $inputs:expr ) => {
+        {
+            use $crate::GroupRef;
+
+            let inputs : Vec<$crate::CreateContributionLinkInput> = $inputs.into_iter()
+                .map( |input| $crate::CreateContributionLinkInput {
+                    group_id: input.entry.group_ref().0,
+                    content_target: input.target.clone().into(),
+                    content_type: input.content_type,
+                    content_base: input.content_base,
+                })
+                .collect();
 
-    if !group.is_contributor( author ) {
-        return Err(format!("Agent ({}) is not authorized to update content managed by group {}", author, group_ref.0 ))?;
-    }
+            let results : ExternResult<Vec<$crate::BatchResult<ActionHash>>> = $crate::call_zome_decode!(
+                $target,
+                $zome,
+                $fn_name,
+                inputs
+            );
 
-    Ok(())
+            results
+        }
+    };
+    ( target = $target:expr; $zome:literal, $inputs:expr ) => {
+        $crate::register_contents_to_group!( target = $target; $zome, "create_content_links", $inputs )
+    };
+    ( target = $target:expr; $inputs:expr ) => {
+        $crate::register_contents_to_group!( target = $target; "coop_content_csr", $inputs )
+    };
+    ( $($def:tt)* ) => {
+        $crate::register_contents_to_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
 }
 
 
-//
-// Zome call helpers
-//
-/// Call a local zome function
+/// Register many content update targets to a group
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <inputs>`
+/// - #2 - `<zome name>, <inputs>`
+/// - #3 - `<inputs>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The inputs are an iterable of [`RegisterContributionUpdateMacroInput`].
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `create_content_update_links`
+///
+/// Items whose target history cannot be traced are reported as errors without being sent.
+///
+/// Returns [`Vec<BatchResult<ActionHash>>`] in the same order as the inputs
+///
+/// #### Examples
+/// All examples assume the setup from [`register_content_update_to_group`] with a list of updated
+/// posts
+/// ```ignore
+/// let updates : Vec<(PostEntry, ActionHash)> = ...;
+/// let inputs : Vec<RegisterContributionUpdateMacroInput<PostEntry>> = updates.into_iter()
+///     .map( |(post, update_addr)| RegisterContributionUpdateMacroInput {
+///         entry: post,
+///         target: update_addr,
+///         respect_locks: false,
+///     })
+///     .collect();
+/// ```
 ///
 /// ##### Example: Basic Usage
+/// ```ignore
+/// let results = register_content_updates_to_group!( inputs )?;
 /// ```
-/// # use coop_content_sdk::*;
-/// # use coop_content_sdk::hdk::prelude::*;
-/// fn example() -> ExternResult<()> {
-///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
-///     let content_addr = "uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0";
 ///
-///     call_local_zome!(
-///         "coop_content_csr",
-///         "create_content_link",
-///         coop_content_sdk::CreateContributionLinkInput {
-///             group_id: ActionHash::try_from(group_id).unwrap(),
-///             content_target: ActionHash::try_from(content_addr).unwrap().into(),
-///             content_type: String::from("post"),
-///         }
-///     )?;
+/// ##### Example: Custom Zome Name
+/// ```ignore
+/// let results = register_content_updates_to_group!(
+///     "coop_content_csr_renamed",
+///     inputs
+/// )?;
+/// ```
 ///
-///     Ok(())
-/// }
+/// ##### Example: Custom Zome and Function Names
+/// ```ignore
+/// let results = register_content_updates_to_group!(
+///     "custom_coop_content_csr",
+///     "register_content_update_links",
+///     inputs
+/// )?;
 /// ```
 #[macro_export]
-macro_rules! call_local_zome {
-    ( $zome:literal, $fn:literal, $($input:tt)+ ) => {
+macro_rules! register_content_updates_to_group {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $inputs:expr ) => {
         {
-            use $crate::hdk;
-            use $crate::hdi_extensions::guest_error;
+            use $crate::hdi_extensions::trace_origin;
+            use $crate::GroupRef;
 
-            match hdk::prelude::call(
-                hdk::prelude::CallTargetCell::Local,
-                $zome,
-                $fn.into(),
-                None,
-                $($input)+,
-            )? {
-                ZomeCallResponse::Ok(extern_io) => Ok(extern_io),
-                ZomeCallResponse::NetworkError(msg) => Err(guest_error!(format!("{}", msg))),
-                ZomeCallResponse::CountersigningSession(msg) => Err(guest_error!(format!("{}", msg))),
-                _ => Err(guest_error!(format!("Zome call response: Unauthorized"))),
+            let mut results : Vec<(usize, $crate::BatchResult<ActionHash>)> = vec![];
+            let mut positions : Vec<usize> = vec![];
+            let mut inputs : Vec<$crate::CreateContributionUpdateLinkInput> = vec![];
+
+            for (index, input) in $inputs.into_iter().enumerate() {
+                let history = match trace_origin( &input.target ) {
+                    Ok(history) => history,
+                    Err(err) => {
+                        results.push(( index, $crate::BatchResult::Err(err.into()) ));
+                        continue;
+                    },
+                };
+
+                if history.len() < 2 {
+                    results.push((
+                        index,
+                        $crate::BatchResult::Err(
+                            $crate::CoopContentError::NotAnUpdate {
+                                address: input.target.to_owned(),
+                            }.into()
+                        ),
+                    ));
+                    continue;
+                }
+
+                positions.push( index );
+                inputs.push( $crate::CreateContributionUpdateLinkInput {
+                    group_id: input.entry.group_ref().0,
+                    content_id: history[ history.len() - 1 ].0.clone().into(),
+                    content_prev: history[1].0.clone().into(),
+                    content_next: input.target.clone().into(),
+                    respect_locks: input.respect_locks,
+                });
             }
+
+            let called : ExternResult<Vec<$crate::BatchResult<ActionHash>>> = match inputs.is_empty() {
+                true => Ok( vec![] ),
+                false => $crate::call_zome_decode!(
+                    $target,
+                    $zome,
+                    $fn_name,
+                    inputs
+                ),
+            };
+
+            called.map( |called| {
+                results.extend( positions.into_iter().zip( called ) );
+                results.sort_by_key( |(index, _)| *index );
+
+                results.into_iter()
+                    .map( |(_, result)| result )
+                    .collect::<Vec<$crate::BatchResult<ActionHash>>>()
+            })
         }
     };
+    ( target = $target:expr; $zome:literal, $inputs:expr ) => {
+        $crate::register_content_updates_to_group!( target = $target; $zome, "create_content_update_links", $inputs )
+    };
+    ( target = $target:expr; $inputs:expr ) => {
+        $crate::register_content_updates_to_group!( target = $target; "coop_content_csr", $inputs )
+    };
+    ( $($def:tt)* ) => {
+        $crate::register_content_updates_to_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
 }
 
-/// Call a local zome function and decode the response
-///
-/// ##### Example: Basic Usage
-/// ```
-/// # use coop_content_sdk::*;
-/// # use coop_content_sdk::hdk::prelude::*;
-/// fn example() -> ExternResult<()> {
-///     let group_id = "uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7";
-///     let content_addr = "uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0";
-///
-///     call_local_zome_decode!(
-///         ActionHash,
-///         "coop_content_csr",
-///         "create_content_link",
-///         coop_content_sdk::CreateContributionLinkInput {
-///             group_id: ActionHash::try_from(group_id).unwrap(),
-///             content_target: ActionHash::try_from(content_addr).unwrap().into(),
-///             content_type: String::from("post"),
-///         }
-///     )?;
-///
-///     Ok(())
-/// }
+
+/// Build a content query macro input from a template, defaulting the fields that can be left out
 ///
-/// ```
+/// `as_of` defaults to `None` and `include_proposals` to `false`; every other field is passed
+/// through as is.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! call_local_zome_decode {
-    ( $zome:literal, $fn:literal, $($input:tt)+ ) => {
-        $crate::call_local_zome!( $zome, $fn, $($input)+ )?
-            .decode()
-            .map_err(|err| hdk::prelude::wasm_error!(hdk::prelude::WasmErrorInner::from(err)) )
+macro_rules! content_query_macro_input {
+    ( $name:ident, { $($fields:tt)* } ) => {
+        $crate::content_query_macro_input!( @munch $name; []; None, false; $($fields)* )
     };
-    ( $into_type:ident, $zome:literal, $fn:literal, $($input:tt)+ ) => {
-        $crate::call_local_zome!( $zome, $fn, $($input)+ )?
-            .decode::<$into_type>()
-            .map_err(|err| hdk::prelude::wasm_error!(hdk::prelude::WasmErrorInner::from(err)) )
+    ( @munch $name:ident; [$($given:tt)*]; $as_of:expr, $proposals:expr; as_of : $value:expr $(, $($rest:tt)*)? ) => {
+        $crate::content_query_macro_input!( @munch $name; [$($given)*]; $value, $proposals; $($($rest)*)? )
+    };
+    ( @munch $name:ident; [$($given:tt)*]; $as_of:expr, $proposals:expr; include_proposals : $value:expr $(, $($rest:tt)*)? ) => {
+        $crate::content_query_macro_input!( @munch $name; [$($given)*]; $as_of, $value; $($($rest)*)? )
+    };
+    ( @munch $name:ident; [$($given:tt)*]; $as_of:expr, $proposals:expr; $field:ident : $value:expr $(, $($rest:tt)*)? ) => {
+        $crate::content_query_macro_input!( @munch $name; [$($given)* $field: $value,]; $as_of, $proposals; $($($rest)*)? )
+    };
+    ( @munch $name:ident; [$($given:tt)*]; $as_of:expr, $proposals:expr; ) => {
+        $crate::$name {
+            $($given)*
+            as_of: $as_of,
+            include_proposals: $proposals,
+        }
     };
 }
 
 
-/// Input required for macros [`register_content_to_group`]
-#[derive(Clone)]
-pub struct RegisterContributionMacroInput<T>
-where
-    T: GroupRef + Clone,
-{
-    /// The content entry belonging to the target
-    pub entry: T,
-    /// An entry creation action address
-    pub target: ActionHash,
-    /// The type name of the target content
-    pub content_type: String,
-    /// Optional base filter of the target content
-    pub content_base: Option<String>,
+/// Input required for macro [`get_group_content_latest`]
+///
+/// `as_of` and `include_proposals` can be left out of the macro template.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetGroupContentMacroInput {
+    pub group_id: ActionHash,
+    pub content_id: AnyLinkableHash,
+    /// Optional point in the group's history (defaults to the latest group state)
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+    /// Include pending content proposals (defaults to the approved view)
+    #[serde(default)]
+    pub include_proposals: bool,
 }
 
 
-/// Register a new content target to a group
+/// Get the latest evolution of a single content target in a group
 ///
 /// Rule patterns
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
-/// The input template is [`RegisterContributionMacroInput`].
+/// The input template is [`GetGroupContentMacroInput`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
-/// - Function name: `create_content_link`
+/// - Function name: `get_group_content_latest_shortcuts`
 ///
 /// Returns [`ActionHash`]
 ///
 /// #### Examples
 /// All examples assume this setup
 /// ```ignore
-/// #[hdk_entry_helper]
-/// struct PostEntry {
-///     pub message: String,
-/// }
+/// let group_id = ActionHash::try_from("uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7").unwrap();
+/// let content_id = ActionHash::try_from("uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0").unwrap();
+/// ```
 ///
-/// #[hdk_entry_defs]
-/// #[unit_enum(EntryTypesUnit)]
-/// pub enum EntryTypes {
-///     #[entry_def]
-///     Post(PostEntry),
-/// }
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let latest_addr = get_group_content_latest!({
+///     group_id: group_id,
+///     content_id: content_id.into(),
+/// })?;
+/// ```
 ///
-/// let post = PostEntry {
-///     message: "Hello world".to_string(),
-/// };
-/// let create_addr = create_entry( EntryTypes::Post(post) )?;
+/// ##### Example: As Of Group Revision
+/// ```ignore
+/// let group_rev = ActionHash::try_from("uhCkkQZ3yb4lSyIDR4kBigHo2H2vEvmvNBzoOdiG2ZfGqzAWAEX4B").unwrap();
+/// let latest_addr = get_group_content_latest!({
+///     group_id: group_id,
+///     content_id: content_id.into(),
+///     as_of: Some(AsOf::Revision(group_rev)),
+/// })?;
 /// ```
 ///
-/// ##### Example: Basic Usage
+/// ##### Example: Including Pending Proposals
 /// ```ignore
-/// let link_addr = register_content_to_group!({
-///     entry: post,
-///     target: create_addr,
+/// let latest_addr = get_group_content_latest!({
+///     group_id: group_id,
+///     content_id: content_id.into(),
+///     include_proposals: true,
 /// })?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let link_addr = register_content_to_group!(
+/// let latest_addr = get_group_content_latest!(
 ///     "coop_content_csr_renamed",
 ///     {
-///         entry: post,
-///         target: create_addr,
+///         group_id: group_id,
+///         content_id: content_id.into(),
 ///     }
 /// )?;
 /// ```
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let link_addr = register_content_to_group!(
+/// let latest_addr = get_group_content_latest!(
 ///     "custom_coop_content_csr",
-///     "register_content_link",
+///     "get_single_group_content",
 ///     {
-///         entry: post,
-///         target: create_addr,
+///         group_id: group_id,
+///         content_id: content_id.into(),
 ///     }
 /// )?;
 /// ```
 #[macro_export]
-macro_rules! register_content_to_group {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+macro_rules! get_group_content_latest {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            use $crate::GroupRef;
-            let input = $crate::RegisterContributionMacroInput $($def)*;
+            use $crate::hdk_extensions;
+            use $crate::hdk_extensions::resolve_action_addr;
+            use $crate::hdi_extensions::trace_origin_root;
 
-            $crate::call_local_zome_decode!(
+            let input = $crate::content_query_macro_input!( GetGroupContentMacroInput, $($def)* );
+            let action_addr = resolve_action_addr( &input.content_id )?;
+
+            // The given 'content_id' must be an ID (create action); not an update action
+            if input.content_id != trace_origin_root( &action_addr )?.0.into() {
+                Err($crate::CoopContentError::NotAnId {
+                    address: action_addr,
+                })?
+            }
+
+            $crate::call_zome_decode!(
+                $target,
                 ActionHash,
                 $zome,
                 $fn_name,
-                $crate::CreateContributionLinkInput {
-                    group_id: input.entry.group_ref().0,
-                    content_target: input.target.clone().into(),
-                    content_type: input.content_type,
-                    content_base: input.content_base,
+                $crate::GetGroupContentInput {
+                    group_id: input.group_id,
+                    content_id: input.content_id,
+                    full_trace: None,
+                    as_of: input.as_of,
+                    include_proposals: input.include_proposals,
                 }
             )
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::register_content_to_group!( $zome, "create_content_link", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group_content_latest!( target = $target; $zome, "get_group_content_latest_shortcuts", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group_content_latest!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::register_content_to_group!( "coop_content_csr", $($def)* )
+        $crate::get_group_content_latest!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
 
-/// Input required for macros [`register_content_update_to_group`]
-#[derive(Clone)]
-pub struct RegisterContributionUpdateMacroInput<T>
-where
-    T: GroupRef + Clone,
-{
-    /// The content entry belonging to the target
-    pub entry: T,
-    /// An entry creation action address
-    pub target: ActionHash,
+/// Input required for macro [`get_all_group_content_latest`]
+///
+/// `as_of` and `include_proposals` can be left out of the macro template.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetAllGroupContentMacroInput {
+    pub group_id: ActionHash,
+    pub content_type: Option<String>,
+    pub content_base: Option<String>,
+    /// Optional point in the group's history (defaults to the latest group state)
+    #[serde(default)]
+    pub as_of: Option<AsOf>,
+    /// Include pending content proposals (defaults to the approved view)
+    #[serde(default)]
+    pub include_proposals: bool,
 }
 
-
-/// Register a content update target to a group
+/// Get the latest evolution of all content targets in a group
 ///
 /// Rule patterns
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
-/// The input template is [`RegisterContributionUpdateMacroInput`].
+/// The input template is [`GetAllGroupContentMacroInput`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
-/// - Function name: `create_content_update_link`
-///
-/// Returns [`ActionHash`]
-///
-/// #### Examples
-/// All examples assume this setup
-/// ```ignore
-/// #[hdk_entry_helper]
-/// struct PostEntry {
-///     pub message: String,
-/// }
+/// - Function name: `get_all_group_content_targets_shortcuts`
 ///
-/// #[hdk_entry_defs]
-/// #[unit_enum(EntryTypesUnit)]
-/// pub enum EntryTypes {
-///     #[entry_def]
-///     Post(PostEntry),
-/// }
+/// The function is called with [`GetAllGroupContentShortcutsInput`].
 ///
-/// let post = PostEntry {
-///     message: "Hello world".to_string(),
-/// };
-/// let create_addr = create_entry( EntryTypes::Post(post) )?;
+/// Returns [`LinkPointerMap`]
 ///
-/// let post_updated = PostEntry {
-///     message: "Hello world (updated)".to_string(),
-/// };
-/// let update_addr = update_entry( create_addr, EntryTypes::Post(post_updated) )?;
+/// #### Examples
+/// All examples assume this setup
+/// ```ignore
+/// let group_id = ActionHash::try_from("uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7").unwrap();
 /// ```
 ///
 /// ##### Example: Basic Usage
 /// ```ignore
-/// let link_addr = register_content_update_to_group!({
-///     entry: post_updated,
-///     target: update_addr,
+/// let link_map = get_all_group_content_latest!({
+///     group_id: group_id,
 /// })?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let link_addr = register_content_update_to_group!(
+/// let link_map = get_all_group_content_latest!(
 ///     "coop_content_csr_renamed",
 ///     {
-///         entry: post_updated,
-///         target: update_addr,
+///         group_id: group_id,
 ///     }
 /// )?;
 /// ```
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let link_addr = register_content_update_to_group!(
+/// let link_map = get_all_group_content_latest!(
 ///     "custom_coop_content_csr",
-///     "register_content_update_link",
+///     "get_all_group_content",
 ///     {
-///         entry: post_updated,
-///         target: update_addr,
+///         group_id: group_id,
 ///     }
 /// )?;
 /// ```
 #[macro_export]
-macro_rules! register_content_update_to_group {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+macro_rules! get_all_group_content_latest {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            use $crate::hdi_extensions::{
-                trace_origin, guest_error,
-            };
-            use $crate::GroupRef;
-
-            let input = $crate::RegisterContributionUpdateMacroInput $($def)*;
-            let history = trace_origin( &input.target )?;
-
-            if history.len() < 2 {
-                Err(guest_error!(format!("History of target {} is empty", input.target )))?
-            }
-
-            let content_id = &history[ history.len() - 1 ].0;
-            let content_prev_rev = &history[1].0;
+            use $crate::hdk;
 
-            $crate::call_local_zome_decode!(
-                ActionHash,
+            type Response = hdk::prelude::ExternResult<$crate::LinkPointerMap>;
+            let input = $crate::content_query_macro_input!( GetAllGroupContentMacroInput, $($def)* );
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
-                $crate::CreateContributionUpdateLinkInput {
-                    group_id: input.entry.group_ref().0,
-                    content_id: content_id.clone().into(),
-                    content_prev: content_prev_rev.clone().into(),
-                    content_next: input.target.clone().into(),
-                }
-            )
+                $crate::GetAllGroupContentShortcutsInput(
+                    input.group_id,
+                    input.content_type,
+                    input.content_base,
+                    input.as_of,
+                    input.include_proposals,
+                )
+            );
+            result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::register_content_update_to_group!( $zome, "create_content_update_link", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_all_group_content_latest!( target = $target; $zome, "get_all_group_content_targets_shortcuts", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_all_group_content_latest!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::register_content_update_to_group!( "coop_content_csr", $($def)* )
+        $crate::get_all_group_content_latest!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
 
-/// Input required for macro [`get_group_content_latest`]
-#[derive(Clone)]
-pub struct GetGroupContentMacroInput {
-    pub group_id: ActionHash,
-    pub content_id: AnyLinkableHash,
-}
-
-
-/// Get the latest evolution of a single content target in a group
+/// Get a group's activity feed
 ///
 /// Rule patterns
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
-/// The input template is [`GetGroupContentMacroInput`].
+/// The input template is [`GetGroupActivityInput`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
-/// - Function name: `get_group_content_latest_shortcuts`
+/// - Function name: `get_group_activity`
 ///
-/// Returns [`ActionHash`]
+/// Returns [`Vec<GroupActivity>`] ordered from oldest to newest
 ///
 /// #### Examples
 /// All examples assume this setup
 /// ```ignore
 /// let group_id = ActionHash::try_from("uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7").unwrap();
-/// let content_id = ActionHash::try_from("uhCkknDrZjzEgzf8iIQ6aEzbqEYrYBBg1pv_iTNUGAFJovhxOJqu0").unwrap();
 /// ```
 ///
 /// ##### Example: Basic Usage
 /// ```ignore
-/// let latest_addr = get_group_content_latest!({
+/// let feed = get_group_activity!({
 ///     group_id: group_id,
-///     content_id: content_id.into(),
+///     content_type: Some("post".to_string()),
+///     author: None,
+///     since: None,
 /// })?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let latest_addr = get_group_content_latest!(
+/// let feed = get_group_activity!(
 ///     "coop_content_csr_renamed",
 ///     {
 ///         group_id: group_id,
-///         content_id: content_id.into(),
+///         content_type: None,
+///         author: None,
+///         since: None,
 ///     }
 /// )?;
 /// ```
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let latest_addr = get_group_content_latest!(
+/// let feed = get_group_activity!(
 ///     "custom_coop_content_csr",
-///     "get_single_group_content",
+///     "get_group_feed",
 ///     {
 ///         group_id: group_id,
-///         content_id: content_id.into(),
+///         content_type: None,
+///         author: None,
+///         since: None,
 ///     }
 /// )?;
 /// ```
 #[macro_export]
-macro_rules! get_group_content_latest {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+macro_rules! get_group_activity {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            use $crate::hdk_extensions;
-            use $crate::hdk_extensions::resolve_action_addr;
-            use $crate::hdi_extensions::{
-                trace_origin, guest_error,
-            };
-
-            let input = $crate::GetGroupContentMacroInput $($def)*;
-            let action_addr = resolve_action_addr( &input.content_id )?;
-            let history = trace_origin( &action_addr )?;
-
-            if history.len() < 1 {
-                Err(guest_error!(format!("Unexpected state")))?
-            }
-
-            if input.content_id != history[ history.len() - 1 ].0.clone().into() {
-                Err(guest_error!(format!("Given 'content_id' must be an ID (create action); not an update action")))?
-            }
+            use $crate::hdk;
 
-            $crate::call_local_zome_decode!(
-                ActionHash,
+            type Response = hdk::prelude::ExternResult<Vec<$crate::GroupActivity>>;
+            let input = $crate::GetGroupActivityInput $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
-                $crate::GetGroupContentInput {
-                    group_id: input.group_id,
-                    content_id: input.content_id,
-                    full_trace: None,
-                }
-            )
+                input
+            );
+            result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::get_group_content_latest!( $zome, "get_group_content_latest_shortcuts", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group_activity!( target = $target; $zome, "get_group_activity", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group_activity!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::get_group_content_latest!( "coop_content_csr", $($def)* )
+        $crate::get_group_activity!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
 
-/// Input required for macro [`get_all_group_content_latest`]
-#[derive(Clone)]
-pub struct GetAllGroupContentMacroInput {
-    pub group_id: ActionHash,
-    pub content_type: Option<String>,
-    pub content_base: Option<String>,
-}
-
-/// Get the latest evolution of all content targets in a group
+/// Get content counts and last activity for a group
 ///
 /// Rule patterns
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
-/// The input template is [`GetAllGroupContentMacroInput`].
+/// The input must be a [`ActionHash`] (group ID).
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
-/// - Function name: `get_all_group_content_targets_shortcuts`
+/// - Function name: `get_group_stats`
 ///
-/// Returns [`LinkPointerMap`]
+/// Returns [`GroupStats`]
 ///
 /// #### Examples
 /// All examples assume this setup
@@ -706,52 +2253,50 @@ pub struct GetAllGroupContentMacroInput {
 ///
 /// ##### Example: Basic Usage
 /// ```ignore
-/// let link_map = get_all_group_content_latest!({
-///     group_id: group_id,
-/// })?;
+/// let stats = get_group_stats!( group_id )?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let link_map = get_all_group_content_latest!(
+/// let stats = get_group_stats!(
 ///     "coop_content_csr_renamed",
-///     {
-///         group_id: group_id,
-///     }
+///     group_id
 /// )?;
 /// ```
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let link_map = get_all_group_content_latest!(
+/// let stats = get_group_stats!(
 ///     "custom_coop_content_csr",
-///     "get_all_group_content",
-///     {
-///         group_id: group_id,
-///     }
+///     "get_group_dashboard",
+///     group_id
 /// )?;
 /// ```
 #[macro_export]
-macro_rules! get_all_group_content_latest {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+macro_rules! get_group_stats {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
             use $crate::hdk;
 
-            type Response = hdk::prelude::ExternResult<$crate::LinkPointerMap>;
-            let input = $crate::GetAllGroupContentMacroInput $($def)*;
-            let result : Response = $crate::call_local_zome_decode!(
+            type Response = hdk::prelude::ExternResult<$crate::GroupStats>;
+            let input : $crate::holo_hash::ActionHash = $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
-                ( input.group_id, input.content_type, input.content_base )
+                input
             );
             result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::get_all_group_content_latest!( $zome, "get_all_group_content_targets_shortcuts", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group_stats!( target = $target; $zome, "get_group_stats", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group_stats!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::get_all_group_content_latest!( "coop_content_csr", $($def)* )
+        $crate::get_group_stats!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
@@ -763,14 +2308,15 @@ macro_rules! get_all_group_content_latest {
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
 /// The input must be a [`GroupEntry`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
 /// - Function name: `create_group`
 ///
-/// Returns [`ActionHash`]
+/// Returns [`Entity<GroupEntry>`]
 ///
 /// #### Examples
 /// All examples assume this setup
@@ -779,6 +2325,9 @@ macro_rules! get_all_group_content_latest {
 ///     admins: vec![ agent_info()?.agent_initial_pubkey ],
 ///     members: vec![],
 ///     deleted: None,
+///     content_types: None,
+///     reviewers: None,
+///     governance_quorum: None,
 ///     published_at: 1688078994936,
 ///     last_updated: 1688078994936,
 ///     metadata: BTreeMap::new(),
@@ -787,12 +2336,12 @@ macro_rules! get_all_group_content_latest {
 ///
 /// ##### Example: Basic Usage
 /// ```ignore
-/// let create_addr = create_group!( group )?;
+/// let group = create_group!( group )?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let create_addr = create_group!(
+/// let group = create_group!(
 ///     "coop_content_csr_renamed",
 ///     group
 /// )?;
@@ -800,7 +2349,7 @@ macro_rules! get_all_group_content_latest {
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let create_addr = create_group!(
+/// let group = create_group!(
 ///     "custom_coop_content_csr",
 ///     "new_group",
 ///     group
@@ -808,22 +2357,29 @@ macro_rules! get_all_group_content_latest {
 /// ```
 #[macro_export]
 macro_rules! create_group {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            let input : GroupEntry = $($def)*;
-            $crate::call_local_zome_decode!(
-                ActionHash,
+            use $crate::hdk;
+
+            type Response = hdk::prelude::ExternResult<$crate::Entity<$crate::GroupEntry>>;
+            let input : $crate::GroupEntry = $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
                 input
-            )
+            );
+            result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::create_group!( $zome, "create_group", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::create_group!( target = $target; $zome, "create_group", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::create_group!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::create_group!( "coop_content_csr", $($def)* )
+        $crate::create_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
@@ -834,14 +2390,15 @@ macro_rules! create_group {
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
 /// The input must be a [`ActionHash`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
 /// - Function name: `get_group`
 ///
-/// Returns [`ActionHash`]
+/// Returns [`Entity<GroupEntry>`]
 ///
 /// #### Examples
 /// All examples assume this setup
@@ -870,24 +2427,185 @@ macro_rules! create_group {
 ///     group_id
 /// )?;
 /// ```
+///
+/// ##### Example: Other Cell With a Capability Secret
+/// ```ignore
+/// let group = get_group!(
+///     target = ZomeCallTarget::from( CallTargetCell::OtherCell( groups_cell_id ) )
+///         .with_cap_secret( cap_secret );
+///     group_id
+/// )?;
+/// ```
 #[macro_export]
 macro_rules! get_group {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            let input : ActionHash = $($def)*;
-            $crate::call_local_zome_decode!(
-                GroupEntry,
+            use $crate::hdk;
+
+            type Response = hdk::prelude::ExternResult<$crate::Entity<$crate::GroupEntry>>;
+            let input : $crate::holo_hash::ActionHash = $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
                 input
-            )
+            );
+            result
+        }
+    };
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group!( target = $target; $zome, "get_group", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group!( target = $target; "coop_content_csr", $($def)* )
+    };
+    ( $($def:tt)* ) => {
+        $crate::get_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
+}
+
+
+/// Get a group's state at a point in its history
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <template>`
+/// - #2 - `<zome name>, <template>`
+/// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The input template is [`GetGroupInput`].
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `get_group_as_of`
+///
+/// Returns [`Entity<GroupEntry>`]
+///
+/// #### Examples
+/// All examples assume this setup
+/// ```ignore
+/// let group_id = ActionHash::try_from("uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7").unwrap();
+/// let timestamp = Timestamp::from_micros( 1688078994936000 );
+/// ```
+///
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let group = get_group_as_of!({
+///     group_id: group_id,
+///     as_of: Some(AsOf::Timestamp(timestamp)),
+/// })?;
+/// ```
+///
+/// ##### Example: Custom Zome Name
+/// ```ignore
+/// let group = get_group_as_of!(
+///     "coop_content_csr_renamed",
+///     {
+///         group_id: group_id,
+///         as_of: Some(AsOf::Timestamp(timestamp)),
+///     }
+/// )?;
+/// ```
+///
+/// ##### Example: Custom Zome and Function Names
+/// ```ignore
+/// let group = get_group_as_of!(
+///     "custom_coop_content_csr",
+///     "get_past_group",
+///     {
+///         group_id: group_id,
+///         as_of: Some(AsOf::Timestamp(timestamp)),
+///     }
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! get_group_as_of {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+        {
+            use $crate::hdk;
+
+            type Response = hdk::prelude::ExternResult<$crate::Entity<$crate::GroupEntry>>;
+            let input = $crate::GetGroupInput $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
+                $zome,
+                $fn_name,
+                input
+            );
+            result
+        }
+    };
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group_as_of!( target = $target; $zome, "get_group_as_of", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group_as_of!( target = $target; "coop_content_csr", $($def)* )
+    };
+    ( $($def:tt)* ) => {
+        $crate::get_group_as_of!( target = $crate::ZomeCallTarget::local(); $($def)* )
+    };
+}
+
+
+/// Get the records that prove a group revision was authorized
+///
+/// Rule patterns
+/// - #1 - `<zome name>, <function name>, <template>`
+/// - #2 - `<zome name>, <template>`
+/// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
+///
+/// The input must be a [`ActionHash`] (group revision).
+///
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
+/// - Zome name: `coop_content_csr`
+/// - Function name: `get_group_membership_proof`
+///
+/// Returns [`GroupMembershipProof`]
+///
+/// #### Examples
+/// All examples assume this setup
+/// ```ignore
+/// let group_rev = ActionHash::try_from("uhCkkQZ3yb4lSyIDR4kBigHo2H2vEvmvNBzoOdiG2ZfGqzAWAEX4B").unwrap();
+/// ```
+///
+/// ##### Example: Basic Usage
+/// ```ignore
+/// let proof = get_group_membership_proof!( group_rev )?;
+/// ```
+///
+/// ##### Example: Other Role
+/// ```ignore
+/// let proof = get_group_membership_proof!(
+///     target = ZomeCallTarget::role("groups");
+///     group_rev
+/// )?;
+/// ```
+#[macro_export]
+macro_rules! get_group_membership_proof {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+        {
+            use $crate::hdk;
+
+            type Response = hdk::prelude::ExternResult<$crate::GroupMembershipProof>;
+            let input : $crate::holo_hash::ActionHash = $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
+                $zome,
+                $fn_name,
+                input
+            );
+            result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::get_group!( $zome, "get_group", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::get_group_membership_proof!( target = $target; $zome, "get_group_membership_proof", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::get_group_membership_proof!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::get_group!( "coop_content_csr", $($def)* )
+        $crate::get_group_membership_proof!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
 
@@ -898,23 +2616,29 @@ macro_rules! get_group {
 /// - #1 - `<zome name>, <function name>, <template>`
 /// - #2 - `<zome name>, <template>`
 /// - #3 - `<template>`
+/// - #4 - `target = <target>;` followed by any of the above (see [`ZomeCallTarget`])
 ///
 /// The input template is [`hdk_extensions::UpdateEntryInput<GroupEntry>`].
 ///
-/// This macro makes a local zome call using these default values:
+/// This macro makes a zome call (to the local cell unless a target is given) using these default values:
 /// - Zome name: `coop_content_csr`
 /// - Function name: `update_group`
 ///
-/// Returns [`ActionHash`]
+/// Returns [`Entity<GroupEntry>`]
 ///
 /// #### Examples
 /// All examples assume this setup
 /// ```ignore
+/// let group_id = ActionHash::try_from("uhCkkrVjqWkvcFoq2Aw4LOSe6Yx9OgQLMNG-DiXqtT0nLx8uIM2j7").unwrap();
 /// let member_id = AgentPubKey::try_from("uhCAkP5vqve5GTqb0-zcVcPsGUFrmp27SMzEoAX1W3HlxYqYesBcN").unwrap();
+/// let group = get_group!( group_id )?;
 /// let group_update = GroupEntry {
 ///     admins: vec![ agent_info()?.agent_initial_pubkey ],
 ///     members: vec![ member_id ],
 ///     deleted: None,
+///     content_types: None,
+///     reviewers: None,
+///     governance_quorum: None,
 ///     published_at: 1688078994936,
 ///     last_updated: 1688090053659,
 ///     metadata: BTreeMap::new(),
@@ -923,18 +2647,18 @@ macro_rules! get_group {
 ///
 /// ##### Example: Basic Usage
 /// ```ignore
-/// let update_addr = update_group!({
-///     base: create_addr,
+/// let updated = update_group!({
+///     base: group.action,
 ///     entry: group_update,
 /// })?;
 /// ```
 ///
 /// ##### Example: Custom Zome Name
 /// ```ignore
-/// let update_addr = update_group!(
+/// let updated = update_group!(
 ///     "coop_content_csr_renamed",
 ///     {
-///         base: create_addr,
+///         base: group.action,
 ///         entry: group_update,
 ///     }
 /// )?;
@@ -942,34 +2666,39 @@ macro_rules! get_group {
 ///
 /// ##### Example: Custom Zome and Function Names
 /// ```ignore
-/// let update_addr = update_group!(
+/// let updated = update_group!(
 ///     "custom_coop_content_csr",
 ///     "fetch_group",
 ///     {
-///         base: create_addr,
+///         base: group.action,
 ///         entry: group_update,
 ///     }
 /// )?;
 /// ```
 #[macro_export]
 macro_rules! update_group {
-    ( $zome:literal, $fn_name:literal, $($def:tt)* ) => {
+    ( target = $target:expr; $zome:literal, $fn_name:literal, $($def:tt)* ) => {
         {
-            use $crate::hdk_extensions;
+            use $crate::hdk;
 
-            let input = hdk_extensions::UpdateEntryInput::<GroupEntry> $($def)*;
-            $crate::call_local_zome_decode!(
-                ActionHash,
+            type Response = hdk::prelude::ExternResult<$crate::Entity<$crate::GroupEntry>>;
+            let input = $crate::hdk_extensions::UpdateEntryInput::<$crate::GroupEntry> $($def)*;
+            let result : Response = $crate::call_zome_decode!(
+                $target,
                 $zome,
                 $fn_name,
                 input
-            )
+            );
+            result
         }
     };
-    ( $zome:literal, $($def:tt)* ) => {
-        $crate::update_group!( $zome, "update_group", $($def)* )
+    ( target = $target:expr; $zome:literal, $($def:tt)* ) => {
+        $crate::update_group!( target = $target; $zome, "update_group", $($def)* )
+    };
+    ( target = $target:expr; $($def:tt)* ) => {
+        $crate::update_group!( target = $target; "coop_content_csr", $($def)* )
     };
     ( $($def:tt)* ) => {
-        $crate::update_group!( "coop_content_csr", $($def)* )
+        $crate::update_group!( target = $crate::ZomeCallTarget::local(); $($def)* )
     };
 }
@@ -0,0 +1,78 @@
+use crate::{
+    hdi,
+    GroupEntry,
+};
+
+use hdi::prelude::*;
+
+
+
+/// The separator between a contribution link tag and the group revision it was created under
+///
+/// Contribution link tags are formatted as `#<content type>#:<content base>` followed by this
+/// separator and a group revision so that tag prefix queries are unaffected.
+pub const CONTRIBUTION_TAG_REV_SEPARATOR : char = '\0';
+
+
+/// A content type declared by a group
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentTypeDeclaration {
+    pub name: String,
+    /// An optional schema identifier or version (eg. `post/v2`)
+    pub schema: Option<String>,
+}
+
+
+/// Check that a content type name is non-empty and cannot break the contribution link tag format
+pub fn check_content_type_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Content type cannot be empty".to_string());
+    }
+
+    if name.contains('#') || name.contains(':') || name.contains( CONTRIBUTION_TAG_REV_SEPARATOR ) {
+        return Err(format!("Content type cannot contain '#', ':', or NUL characters: {:?}", name ));
+    }
+
+    Ok(())
+}
+
+
+/// Split a link tag into the part before [`CONTRIBUTION_TAG_REV_SEPARATOR`] and the group revision
+/// after it (if any)
+pub fn split_contribution_tag(tag: &str) -> (&str, Option<&str>) {
+    match tag.split_once( CONTRIBUTION_TAG_REV_SEPARATOR ) {
+        Some((tag, group_rev)) => (tag, Some(group_rev)),
+        None => (tag, None),
+    }
+}
+
+
+impl GroupEntry {
+    /// Check that this group's declared content types have valid, unique names
+    pub fn check_content_types(&self) -> Result<(), String> {
+        let declarations = match &self.content_types {
+            Some(declarations) => declarations,
+            None => return Ok(()),
+        };
+
+        for (index, declaration) in declarations.iter().enumerate() {
+            check_content_type_name( &declaration.name )?;
+
+            if declarations[..index].iter().any( |other| other.name == declaration.name ) {
+                return Err(format!("Content type '{}' is declared more than once", declaration.name ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if this group allows contributions of the given content type
+    ///
+    /// Groups without declarations allow any content type.
+    pub fn allows_content_type(&self, content_type: &str) -> bool {
+        match &self.content_types {
+            Some(declarations) => declarations.iter().any( |declaration| declaration.name == content_type ),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::hdi;
+use crate::GroupEntry;
+
+use hdi::prelude::*;
+
+
+
+/// The number of characters used for a directory name index
+pub const DIRECTORY_NAME_PREFIX_LENGTH : usize = 3;
+
+/// The metadata key for a group's directory name
+pub const DIRECTORY_NAME_KEY : &str = "name";
+
+/// The metadata key for a group's directory tags
+pub const DIRECTORY_TAGS_KEY : &str = "tags";
+
+
+/// The directory indexes that a group can be listed under
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DirectoryIndex {
+    /// Every listed group
+    All,
+    /// Groups whose (lowercase) name starts with this prefix
+    Name(String),
+    /// Groups that have this (lowercase) tag
+    Tag(String),
+}
+
+impl DirectoryIndex {
+    /// Create a name index for the given search term (or name)
+    ///
+    /// Returns `None` if the term is shorter than [`DIRECTORY_NAME_PREFIX_LENGTH`].
+    pub fn name(term: &str) -> Option<Self> {
+        let prefix : String = term.trim().to_lowercase().chars()
+            .take( DIRECTORY_NAME_PREFIX_LENGTH )
+            .collect();
+
+        match prefix.chars().count() == DIRECTORY_NAME_PREFIX_LENGTH {
+            true => Some( DirectoryIndex::Name( prefix ) ),
+            false => None,
+        }
+    }
+
+    /// Create a tag index for the given tag
+    pub fn tag(tag: &str) -> Self {
+        DirectoryIndex::Tag( tag.trim().to_lowercase() )
+    }
+
+    /// Get the path for this index
+    pub fn path(&self) -> Path {
+        let anchor = match self {
+            DirectoryIndex::All => "directory:all".to_string(),
+            DirectoryIndex::Name(prefix) => format!("directory:name:{}", prefix ),
+            DirectoryIndex::Tag(tag) => format!("directory:tag:{}", tag ),
+        };
+
+        Path::from(vec![
+            Component::from( anchor.as_bytes().to_vec() )
+        ])
+    }
+
+    /// Get the path entry hash for this index
+    pub fn path_entry_hash(&self) -> ExternResult<EntryHash> {
+        self.path().path_entry_hash()
+    }
+}
+
+
+impl GroupEntry {
+    /// Get the directory name from this group's metadata
+    pub fn directory_name(&self) -> Option<String> {
+        self.metadata.get( DIRECTORY_NAME_KEY )
+            .and_then( |value| value.as_str() )
+            .map( |name| name.trim().to_string() )
+            .filter( |name| !name.is_empty() )
+    }
+
+    /// Get the (lowercase) directory tags from this group's metadata
+    pub fn directory_tags(&self) -> Vec<String> {
+        let mut tags : Vec<String> = self.metadata.get( DIRECTORY_TAGS_KEY )
+            .and_then( |value| value.as_array() )
+            .map( |values| {
+                values.iter()
+                    .filter_map( |value| value.as_str() )
+                    .map( |tag| tag.trim().to_lowercase() )
+                    .filter( |tag| !tag.is_empty() )
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Get every directory index that this group should be listed under
+    pub fn directory_indexes(&self) -> Vec<DirectoryIndex> {
+        let mut indexes = vec![ DirectoryIndex::All ];
+
+        if let Some(index) = self.directory_name().as_deref().and_then( DirectoryIndex::name ) {
+            indexes.push( index );
+        }
+
+        for tag in self.directory_tags() {
+            indexes.push( DirectoryIndex::tag( &tag ) );
+        }
+
+        indexes
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+
+use std::fmt;
+use hdi::prelude::*;
+use hdi_extensions::guest_error;
+
+
+
+/// The marker that precedes the encoded error in an error message
+///
+/// Messages are formatted as `<Variant>: <description> [coop_content_error:<hex msgpack>]`.  The
+/// encoded part only uses hex characters so that it survives being nested in host error messages.
+pub const ERROR_PAYLOAD_MARKER : &str = "[coop_content_error:";
+
+
+/// Errors that callers are expected to recognize and handle
+///
+/// Converted to a guest [`WasmError`] (or a validation message) whose message starts with the
+/// variant name (eg. `StaleRevision: ...`) so that clients can match on it.  The message also
+/// carries the encoded error so that it can be decoded using [`CoopContentError::from_message`] or
+/// `CoopContentError::try_from( &wasm_error )`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoopContentError {
+    /// A group update was based on a revision that has already been updated
+    StaleRevision {
+        base: ActionHash,
+        latest: ActionHash,
+    },
+    /// An action hash was expected to be the create action of a group or content
+    NotAnId {
+        address: ActionHash,
+    },
+    /// An action hash was expected to be an update action
+    NotAnUpdate {
+        address: ActionHash,
+    },
+    /// An action hash is not a revision of the given group or content ID
+    NotARevision {
+        id: ActionHash,
+        address: ActionHash,
+    },
+    /// An agent is not an admin of the group
+    NotAnAdmin {
+        group_id: ActionHash,
+        agent: AgentPubKey,
+        /// What the agent was trying to do (eg. `merge its forks`)
+        operation: String,
+    },
+    /// An agent is not a member of the group (admins are not members)
+    NotAMember {
+        group_id: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// An agent is not an admin or member of the group
+    NotAContributor {
+        group_id: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// An agent is not an admin or declared reviewer of the group
+    NotAReviewer {
+        group_id: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// A link tag could not be parsed
+    MalformedTag {
+        link_type: String,
+        reason: String,
+    },
+    /// The group has been marked as deleted
+    GroupDeleted {
+        group_id: ActionHash,
+    },
+    /// A content type is not declared by the group revision
+    UndeclaredContentType {
+        content_type: String,
+        group_rev: ActionHash,
+    },
+    /// A content ID was not found in the group's content
+    ContentNotFound {
+        group_id: ActionHash,
+        content_id: AnyLinkableHash,
+    },
+    /// Another agent holds an unexpired lock on the content
+    ContentLocked {
+        group_id: ActionHash,
+        content_id: AnyLinkableHash,
+        holder: AgentPubKey,
+        expires_at: Timestamp,
+    },
+    /// A governance proposal does not have enough approving votes
+    QuorumNotReached {
+        proposal: ActionHash,
+        approvals: usize,
+        quorum: usize,
+    },
+    /// Governance changes cannot be applied to the group revision
+    InvalidGovernanceChanges {
+        group_id: ActionHash,
+        reason: String,
+    },
+    /// An agent has already voted on the governance proposal
+    AlreadyVoted {
+        proposal: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// A governance proposal has already been committed
+    AlreadyCommitted {
+        proposal: ActionHash,
+        committed: ActionHash,
+    },
+    /// An agent was not removed by the group revision
+    NotRemoved {
+        group_rev: ActionHash,
+        agent: AgentPubKey,
+    },
+    /// An action hash is not a content proposal link
+    NotAProposal {
+        address: ActionHash,
+    },
+    /// The group does not have any unmerged forks
+    NoUnmergedForks {
+        group_id: ActionHash,
+    },
+}
+
+impl CoopContentError {
+    /// Format this error as a message that includes the encoded error
+    pub fn to_message(&self) -> String {
+        match encode( self ) {
+            Ok(bytes) => format!(
+                "{} {}{}]",
+                self, ERROR_PAYLOAD_MARKER,
+                bytes.iter().map( |byte| format!("{:02x}", byte ) ).collect::<String>(),
+            ),
+            Err(_) => self.to_string(),
+        }
+    }
+
+    /// Decode the error from a message created by [`CoopContentError::to_message`]
+    ///
+    /// The encoded part can be anywhere in the message (eg. nested in a host error or a validation
+    /// failure).  Returns `None` if the message does not contain an encoded error.
+    pub fn from_message(message: &str) -> Option<Self> {
+        let start = message.find( ERROR_PAYLOAD_MARKER )? + ERROR_PAYLOAD_MARKER.len();
+        let hex = &message[start..];
+        let hex = &hex[ ..hex.find(']')? ];
+
+        if !hex.len().is_multiple_of( 2 ) || !hex.is_ascii() {
+            return None;
+        }
+
+        let bytes = (0..hex.len()).step_by(2)
+            .map( |i| u8::from_str_radix( &hex[i..i+2], 16 ) )
+            .collect::<Result<Vec<u8>, _>>()
+            .ok()?;
+
+        decode( &bytes ).ok()
+    }
+}
+
+impl fmt::Display for CoopContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoopContentError::StaleRevision { base, latest } => write!(
+                f, "StaleRevision: group revision '{}' is not the latest; latest is '{}'",
+                base, latest,
+            ),
+            CoopContentError::NotAnId { address } => write!(
+                f, "NotAnId: action hash '{}' is not an ID",
+                address,
+            ),
+            CoopContentError::NotAnUpdate { address } => write!(
+                f, "NotAnUpdate: action hash '{}' is not an update",
+                address,
+            ),
+            CoopContentError::NotARevision { id, address } => write!(
+                f, "NotARevision: action hash '{}' is not a revision of '{}'",
+                address, id,
+            ),
+            CoopContentError::NotAnAdmin { group_id, operation, .. } => write!(
+                f, "NotAnAdmin: Only admins of group '{}' can {}",
+                group_id, operation,
+            ),
+            CoopContentError::NotAMember { group_id, agent } => write!(
+                f, "NotAMember: agent '{}' is not a member of group '{}'",
+                agent, group_id,
+            ),
+            CoopContentError::NotAContributor { group_id, agent } => write!(
+                f, "NotAContributor: agent '{}' is not authorized to update content managed by group '{}'",
+                agent, group_id,
+            ),
+            CoopContentError::NotAReviewer { group_id, agent } => write!(
+                f, "NotAReviewer: agent '{}' is not authorized to review content proposals in group '{}'",
+                agent, group_id,
+            ),
+            CoopContentError::MalformedTag { link_type, reason } => write!(
+                f, "MalformedTag: {} link has a malformed tag: {}",
+                link_type, reason,
+            ),
+            CoopContentError::GroupDeleted { group_id } => write!(
+                f, "GroupDeleted: group '{}' is deleted",
+                group_id,
+            ),
+            CoopContentError::UndeclaredContentType { content_type, group_rev } => write!(
+                f, "UndeclaredContentType: Content type '{}' is not declared by group revision '{}'",
+                content_type, group_rev,
+            ),
+            CoopContentError::ContentNotFound { group_id, content_id } => write!(
+                f, "ContentNotFound: content ID '{}' is not in group '{}'",
+                content_id, group_id,
+            ),
+            CoopContentError::ContentLocked { group_id, content_id, holder, expires_at } => write!(
+                f, "ContentLocked: content ID '{}' in group '{}' is locked by agent '{}' until {:?}",
+                content_id, group_id, holder, expires_at,
+            ),
+            CoopContentError::QuorumNotReached { proposal, approvals, quorum } => write!(
+                f, "QuorumNotReached: governance proposal '{}' has {} of {} required approvals",
+                proposal, approvals, quorum,
+            ),
+            CoopContentError::InvalidGovernanceChanges { group_id, reason } => write!(
+                f, "InvalidGovernanceChanges: changes cannot be applied to group '{}': {}",
+                group_id, reason,
+            ),
+            CoopContentError::AlreadyVoted { proposal, agent } => write!(
+                f, "AlreadyVoted: agent '{}' has already voted on governance proposal '{}'",
+                agent, proposal,
+            ),
+            CoopContentError::AlreadyCommitted { proposal, committed } => write!(
+                f, "AlreadyCommitted: governance proposal '{}' was already committed by revision '{}'",
+                proposal, committed,
+            ),
+            CoopContentError::NotRemoved { group_rev, agent } => write!(
+                f, "NotRemoved: agent '{}' was not removed by group revision '{}'",
+                agent, group_rev,
+            ),
+            CoopContentError::NotAProposal { address } => write!(
+                f, "NotAProposal: action hash '{}' is not a content proposal",
+                address,
+            ),
+            CoopContentError::NoUnmergedForks { group_id } => write!(
+                f, "NoUnmergedForks: group '{}' has no unmerged forks",
+                group_id,
+            ),
+        }
+    }
+}
+
+impl From<CoopContentError> for WasmError {
+    fn from(error: CoopContentError) -> Self {
+        guest_error!( error.to_message() )
+    }
+}
+
+impl From<CoopContentError> for String {
+    fn from(error: CoopContentError) -> Self {
+        error.to_message()
+    }
+}
+
+impl TryFrom<&WasmError> for CoopContentError {
+    type Error = ();
+
+    /// Decode the error from a guest error or a host error that wraps one (eg. a failed zome call)
+    fn try_from(error: &WasmError) -> Result<Self, Self::Error> {
+        let message = match &error.error {
+            WasmErrorInner::Guest(message) | WasmErrorInner::Host(message) => message.to_owned(),
+            other => format!("{:?}", other ),
+        };
+
+        CoopContentError::from_message( &message ).ok_or(())
+    }
+}
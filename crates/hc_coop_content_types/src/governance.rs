@@ -0,0 +1,154 @@
+use crate::{
+    hdi,
+    GroupEntry,
+};
+
+use std::collections::BTreeMap;
+use hdi::prelude::*;
+
+
+
+/// The maximum quorum so that the votes of a commit fit in its link tag
+pub const GOVERNANCE_MAX_QUORUM : usize = 20;
+
+/// The maximum size (in bytes) of a governance proposal description
+pub const GOVERNANCE_DESCRIPTION_MAX_BYTES : usize = 1024;
+
+
+/// The group changes of a governance proposal
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GovernanceChanges {
+    #[serde(default)]
+    pub add_members: Vec<AgentPubKey>,
+    #[serde(default)]
+    pub remove_members: Vec<AgentPubKey>,
+    /// Metadata values to set (`null` removes the key)
+    #[serde(default)]
+    pub metadata: BTreeMap<String, Option<rmpv::Value>>,
+}
+
+impl GovernanceChanges {
+    /// Check that these changes are not empty and can be applied to the given group
+    pub fn check(&self, group: &GroupEntry) -> Result<(), String> {
+        if self.add_members.is_empty() && self.remove_members.is_empty() && self.metadata.is_empty() {
+            return Err("Governance proposal must change something".to_string());
+        }
+
+        for (index, agent) in self.add_members.iter().enumerate() {
+            if group.is_contributor( agent ) || self.add_members[..index].contains( agent ) {
+                return Err(format!("Cannot add agent '{}' because they are already a contributor", agent ));
+            }
+        }
+
+        for agent in self.remove_members.iter() {
+            if !group.is_member( agent ) {
+                return Err(format!("Cannot remove agent '{}' because they are not a member", agent ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the given group with these changes applied (`last_updated` is unchanged)
+    pub fn apply(&self, group: &GroupEntry) -> GroupEntry {
+        let mut next = group.to_owned();
+
+        next.members.retain( |member| !self.remove_members.contains( member ) );
+        next.members.extend( self.add_members.iter().cloned() );
+
+        for (key, value) in self.metadata.iter() {
+            match value {
+                Some(value) => next.metadata.insert( key.to_owned(), value.to_owned() ),
+                None => next.metadata.remove( key ),
+            };
+        }
+
+        next
+    }
+}
+
+
+/// An entry struct for proposing changes to a group
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct GovernanceProposalEntry {
+    /// The group revision that the changes apply to
+    pub group_rev: ActionHash,
+    pub changes: GovernanceChanges,
+    /// An optional explanation for the voters
+    pub description: Option<String>,
+}
+
+
+/// An entry struct for a contributor's vote on a governance proposal
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct GovernanceVoteEntry {
+    /// The create action of the proposal
+    pub proposal: ActionHash,
+    pub approve: bool,
+}
+
+
+/// The link tag for a governance commit link
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct GovernanceCommitTag {
+    /// The approving votes counted for the commit
+    pub votes: Vec<ActionHash>,
+}
+
+impl GovernanceCommitTag {
+    /// Encode this tag as a [`LinkTag`]
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = SerializedBytes::try_from( self.to_owned() )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )?;
+
+        Ok( LinkTag::new( bytes.bytes().to_vec() ) )
+    }
+}
+
+impl TryFrom<LinkTag> for GovernanceCommitTag {
+    type Error = WasmError;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        GovernanceCommitTag::try_from( SerializedBytes::from( UnsafeBytes::from( tag.into_inner() ) ) )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )
+    }
+}
+
+
+impl GroupEntry {
+    /// Get the number of approving votes a governance proposal for this group revision needs
+    ///
+    /// Groups without a `governance_quorum` need a majority of their contributors (capped at
+    /// [`GOVERNANCE_MAX_QUORUM`]).
+    pub fn governance_quorum(&self) -> Result<usize, String> {
+        let quorum = match self.governance_quorum {
+            Some(quorum) => quorum,
+            None => return Ok( ( self.contributors().len() / 2 + 1 ).min( GOVERNANCE_MAX_QUORUM ) ),
+        };
+
+        if quorum == 0 || quorum > GOVERNANCE_MAX_QUORUM {
+            return Err(format!(
+                "Governance quorum must be 1-{}; not {}",
+                GOVERNANCE_MAX_QUORUM, quorum,
+            ));
+        }
+
+        Ok( quorum )
+    }
+
+    /// Check if the only change from this group to the given group is the given governance changes
+    pub fn is_governance_change(&self, next: &GroupEntry, changes: &GovernanceChanges) -> bool {
+        let expected = changes.apply( self );
+
+        next.members == expected.members
+            && next.admins == expected.admins
+            && next.deleted == expected.deleted
+            && next.content_types == expected.content_types
+            && next.reviewers == expected.reviewers
+            && next.governance_quorum == expected.governance_quorum
+            && next.published_at == expected.published_at
+            && next.metadata == expected.metadata
+    }
+}
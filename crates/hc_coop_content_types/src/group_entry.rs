@@ -1,4 +1,7 @@
-use crate::hdi;
+use crate::{
+    hdi,
+    ContentTypeDeclaration,
+};
 
 use std::collections::BTreeMap;
 use hdi::prelude::*;
@@ -64,6 +67,19 @@ pub struct GroupEntry {
     pub members: Vec<AgentPubKey>,
     /// An indicator of whether this group is still active
     pub deleted: Option<bool>,
+    /// The content types that contributions must use (`None` allows any content type)
+    #[serde(default)]
+    pub content_types: Option<Vec<ContentTypeDeclaration>>,
+    /// The contributors who can approve contribution proposals along with the admins
+    ///
+    /// Groups with reviewers (even an empty list) require updates from members who are not
+    /// reviewers to be proposed and approved by an admin or reviewer.
+    #[serde(default)]
+    pub reviewers: Option<Vec<AgentPubKey>>,
+    /// The number of approving votes a governance proposal needs (1 to
+    /// [`GOVERNANCE_MAX_QUORUM`](crate::GOVERNANCE_MAX_QUORUM))
+    #[serde(default)]
+    pub governance_quorum: Option<usize>,
 
     // common fields
     pub published_at: u64,
@@ -96,6 +112,25 @@ impl GroupEntry {
         self.members.contains( agent )
     }
 
+    /// Check if the only change from this group to the given group is the given member removing
+    /// themselves
+    pub fn is_self_removal(&self, next: &GroupEntry, agent: &AgentPubKey) -> bool {
+        let expected_members : Vec<AgentPubKey> = self.members.iter()
+            .filter( |member| *member != agent )
+            .cloned()
+            .collect();
+
+        self.is_member( agent )
+            && next.members == expected_members
+            && next.admins == self.admins
+            && next.deleted == self.deleted
+            && next.content_types == self.content_types
+            && next.reviewers == self.reviewers
+            && next.governance_quorum == self.governance_quorum
+            && next.published_at == self.published_at
+            && next.metadata == self.metadata
+    }
+
     /// Return the differences between this group and the given group
     pub fn contributors_diff(&self, other: &GroupEntry) -> ContributorsDiff {
         let added: Vec<AgentPubKey> = other.contributors()
@@ -119,6 +154,24 @@ impl GroupEntry {
             intersection,
         }
     }
+
+    /// Return the metadata keys that were added, changed, or removed in the given group
+    pub fn metadata_changes(&self, other: &GroupEntry) -> Vec<MetadataChange> {
+        let mut keys : Vec<&String> = self.metadata.keys()
+            .chain( other.metadata.keys() )
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        keys.into_iter()
+            .filter( |key| self.metadata.get( *key ) != other.metadata.get( *key ) )
+            .map( |key| MetadataChange {
+                key: key.to_owned(),
+                previous: self.metadata.get( key ).cloned(),
+                current: other.metadata.get( key ).cloned(),
+            })
+            .collect()
+    }
 }
 
 /// The result of a group comparison
@@ -129,6 +182,16 @@ pub struct ContributorsDiff {
     pub intersection: Vec<AgentPubKey>,
 }
 
+/// A metadata key that differs between two groups
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetadataChange {
+    pub key: String,
+    /// The previous value (`None` if the key was added)
+    pub previous: Option<rmpv::Value>,
+    /// The current value (`None` if the key was removed)
+    pub current: Option<rmpv::Value>,
+}
+
 
 
 //
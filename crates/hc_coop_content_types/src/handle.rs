@@ -0,0 +1,83 @@
+use crate::hdi;
+
+use hdi::prelude::*;
+
+
+
+/// The minimum length of a group handle
+pub const GROUP_HANDLE_MIN_LENGTH : usize = 3;
+
+/// The maximum length of a group handle
+pub const GROUP_HANDLE_MAX_LENGTH : usize = 64;
+
+
+/// Check that a handle is lowercase ASCII letters, digits, and inner dashes (eg. `acme-docs`)
+pub fn check_group_handle(handle: &str) -> Result<(), String> {
+    let length = handle.len();
+
+    if !(GROUP_HANDLE_MIN_LENGTH..=GROUP_HANDLE_MAX_LENGTH).contains( &length ) {
+        return Err(format!(
+            "Group handle must be {}-{} characters long; not {}",
+            GROUP_HANDLE_MIN_LENGTH, GROUP_HANDLE_MAX_LENGTH, length,
+        ));
+    }
+
+    if !handle.chars().all( |c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' ) {
+        return Err(format!("Group handle can only contain lowercase letters, digits, and dashes: {}", handle ));
+    }
+
+    if handle.starts_with('-') || handle.ends_with('-') {
+        return Err(format!("Group handle cannot start or end with a dash: {}", handle ));
+    }
+
+    Ok(())
+}
+
+
+/// Get the anchor path for a group handle
+pub fn group_handle_path(handle: &str) -> Path {
+    Path::from(vec![
+        Component::from( format!("handle:{}", handle ).as_bytes().to_vec() )
+    ])
+}
+
+
+/// A reference to the handle link being transferred and the authority to transfer it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupHandleTransfer {
+    /// The create link action of the previous handle link
+    pub link: ActionHash,
+    /// A revision of the previous handle's group where the transfer author is an admin
+    pub group_rev: ActionHash,
+}
+
+
+/// The link tag for a group handle link
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct GroupHandleTag {
+    /// The claimed handle
+    pub handle: String,
+    /// A revision of the target group where the link author is an admin
+    pub group_rev: ActionHash,
+    /// The previous handle link when this link is a transfer
+    pub previous: Option<GroupHandleTransfer>,
+}
+
+impl GroupHandleTag {
+    /// Encode this tag as a [`LinkTag`]
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = SerializedBytes::try_from( self.to_owned() )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )?;
+
+        Ok( LinkTag::new( bytes.bytes().to_vec() ) )
+    }
+}
+
+impl TryFrom<LinkTag> for GroupHandleTag {
+    type Error = WasmError;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        GroupHandleTag::try_from( SerializedBytes::from( UnsafeBytes::from( tag.into_inner() ) ) )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )
+    }
+}
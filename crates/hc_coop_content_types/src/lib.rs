@@ -1,6 +1,21 @@
 mod group_entry;
+mod directory;
+mod handle;
+mod content_types;
+mod locks;
+mod review;
+mod governance;
+mod error;
 
+pub use rmpv;
 pub use hdi_extensions;
 pub use hdi_extensions::hdi;
 
 pub use group_entry::*;
+pub use directory::*;
+pub use handle::*;
+pub use content_types::*;
+pub use locks::*;
+pub use review::*;
+pub use governance::*;
+pub use error::*;
@@ -0,0 +1,40 @@
+use crate::hdi;
+
+use hdi::prelude::*;
+
+
+
+/// The default duration (in seconds) of a content lock
+pub const CONTENT_LOCK_DEFAULT_DURATION : u64 = 15 * 60;
+
+/// The maximum duration (in seconds) of a content lock
+pub const CONTENT_LOCK_MAX_DURATION : u64 = 24 * 60 * 60;
+
+
+/// The link tag for a content lock link
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct ContentLockTag {
+    /// A revision of the target group where the link author is a contributor
+    pub group_rev: ActionHash,
+    /// The time when the lock stops being effective
+    pub expires_at: Timestamp,
+}
+
+impl ContentLockTag {
+    /// Encode this tag as a [`LinkTag`]
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = SerializedBytes::try_from( self.to_owned() )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )?;
+
+        Ok( LinkTag::new( bytes.bytes().to_vec() ) )
+    }
+}
+
+impl TryFrom<LinkTag> for ContentLockTag {
+    type Error = WasmError;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        ContentLockTag::try_from( SerializedBytes::from( UnsafeBytes::from( tag.into_inner() ) ) )
+            .map_err( |err| wasm_error!(WasmErrorInner::Serialize(err)) )
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    GroupEntry,
+    CONTRIBUTION_TAG_REV_SEPARATOR,
+    split_contribution_tag,
+};
+
+use hdi::prelude::*;
+use hdi_extensions::AnyLinkableHashTransformer;
+
+
+
+impl GroupEntry {
+    /// Check if this group requires updates to be reviewed
+    pub fn requires_review(&self) -> bool {
+        self.reviewers.is_some()
+    }
+
+    /// Check if the given agent can approve proposals (an admin or a contributor declared as a
+    /// reviewer)
+    pub fn is_reviewer(&self, agent: &AgentPubKey) -> bool {
+        self.is_admin( agent ) || (
+            self.is_contributor( agent )
+                && self.reviewers.as_ref().is_some_and( |reviewers| reviewers.contains( agent ) )
+        )
+    }
+
+    /// Check if the given agent's updates must be proposed instead of linked directly
+    pub fn requires_review_for(&self, agent: &AgentPubKey) -> bool {
+        self.requires_review() && !self.is_reviewer( agent )
+    }
+}
+
+
+/// The link tag for a contribution proposal link
+///
+/// Formatted as a contribution update tag (`<content ID>:<previous revision>`) followed by
+/// [`CONTRIBUTION_TAG_REV_SEPARATOR`] and the group revision.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContributionProposalTag {
+    pub content_id: AnyLinkableHash,
+    pub content_prev: AnyLinkableHash,
+    /// A revision of the anchor's group where the link author is a contributor
+    pub group_rev: ActionHash,
+}
+
+impl ContributionProposalTag {
+    /// Get the contribution update tag for approving this proposal
+    pub fn update_tag(&self) -> String {
+        format!("{}:{}", self.content_id, self.content_prev )
+    }
+
+    /// Encode this tag as a [`LinkTag`]
+    pub fn to_link_tag(&self) -> LinkTag {
+        LinkTag::new( format!("{}{}{}", self.update_tag(), CONTRIBUTION_TAG_REV_SEPARATOR, self.group_rev ).into_bytes() )
+    }
+}
+
+impl TryFrom<LinkTag> for ContributionProposalTag {
+    type Error = String;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        let tag_str = String::from_utf8( tag.into_inner() )
+            .map_err( |err| format!("must be a UTF8 string: {}", err ) )?;
+        let (update_tag, group_rev) = match split_contribution_tag( &tag_str ) {
+            (update_tag, Some(group_rev)) => (update_tag, group_rev),
+            _ => return Err("missing group revision".to_string()),
+        };
+        let (content_id, content_prev) = update_tag.split_once(":")
+            .ok_or( format!("expected '<content ID>:<previous revision>'; not {:?}", update_tag ) )?;
+
+        Ok( ContributionProposalTag {
+            content_id: AnyLinkableHash::try_from_string( content_id )
+                .map_err( |err| format!("invalid content ID: {:?}", err ) )?,
+            content_prev: AnyLinkableHash::try_from_string( content_prev )
+                .map_err( |err| format!("invalid previous revision: {:?}", err ) )?,
+            group_rev: ActionHash::try_from( group_rev.to_string() )
+                .map_err( |err| format!("invalid group revision: {:?}", err ) )?,
+        })
+    }
+}
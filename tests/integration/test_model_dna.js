@@ -29,6 +29,7 @@ import {
     linearSuite,
     createGroupInput,
     createContentInput,
+    createCommentInput,
     delay,
 }					from '../utils.js';
 import {
@@ -402,7 +403,7 @@ function phase1_checks_tests () {
 		"group_rev": group.$id,
 		"anchor_agent": david_client.agent_id,
 	    });
-	}, "contributions anchor must match a contributor in the group base" );
+	}, "NotAContributor" );
     });
 
     // Dynamic
@@ -412,7 +413,7 @@ function phase1_checks_tests () {
 		"base": group.$id,
 		"entry": group,
 	    });
-	}, "group can only be done by an admin" );
+	}, "NotAnAdmin" );
     });
 
     it("should reject content update because agent is not in the group's contributors", async function () {
@@ -433,7 +434,7 @@ function phase1_checks_tests () {
 		"group_rev": group.$id,
 		"anchor_agent": alice_client.agent_id,
 	    });
-	}, "author of a group auth link must be an admin of the base group" );
+	}, "NotAnAdmin" );
     });
 
 }
@@ -555,7 +556,7 @@ function phase2_checks_tests () {
 		"archived_agent": carol_client.agent_id,
 		"target": c2aa_addr,
 	    });
-	}, "auth archive anchor can only be made by group admins" );
+	}, "NotAnAdmin" );
     });
 
     it("should reject auth anchor link because agent (A3 + A4) is not an admin", async function () {
@@ -565,7 +566,7 @@ function phase2_checks_tests () {
 		"group_rev": g1a_addr,
 		"anchor_agent": alice_client.agent_id,
 	    });
-	}, "author of a group auth link must be an admin of the base group" );
+	}, "NotAnAdmin" );
 
 	await expect_reject( async () => {
 	    await david_client.call( DNA_NAME, EVIL_ZOME, "invalid_group_auth_link", {
@@ -573,7 +574,7 @@ function phase2_checks_tests () {
 		"group_rev": g1a_addr,
 		"anchor_agent": alice_client.agent_id,
 	    });
-	}, "author of a group auth link must be an admin of the base group" );
+	}, "NotAnAdmin" );
     });
 
     it("should reject content update because agent (A4) did not update the author group revision", async function () {
@@ -705,6 +706,922 @@ function phase3_tests () {
 	}
     });
 
+    it("should get group as of revision (G1a)", async function () {
+	const g1a			= await david_coop_content.get_group_as_of({
+	    "group_id": group.$id,
+	    "as_of": {
+		"revision": g1a_addr,
+	    },
+	});
+	log.debug( json.debug( g1a ) );
+
+	expect( String(g1a.$action)	).to.equal( String(g1a_addr) );
+	expect( g1a.isMember( carol_client.agent_id ) ).to.be.false;
+	expect( g1a.isMember( david_client.agent_id ) ).to.be.true;
+    });
+
+    it("should get group content as of revision (G1) and find: C1a, C2a, C3", async function () {
+	const targets			= new Set(
+	    (await david_coop_content.get_all_group_content_targets({
+		"group_id": group.$id,
+		"as_of": {
+		    "revision": group.$id,
+		},
+	    }))
+		.map( pair => String( pair[1] ) )
+	);
+	log.debug("Group content targets: %s", targets );
+
+	const expected_targets	= [
+	    c1a_addr,
+	    c2a_addr,
+	    c3_addr,
+	].map( addr => String(new HoloHash(addr)) );
+	expect( targets			).to.have.all.keys( ...expected_targets );
+	expect( targets			).to.have.lengthOf( expected_targets.length );
+    });
+
+    it("should get group activity for carol (A3)", async function () {
+	const feed			= await david_coop_content.get_group_activity({
+	    "group_id": group.$id,
+	    "author": carol_client.agent_id,
+	});
+	log.debug("Group activity: %s", json.debug( feed ) );
+
+	const created			= feed.filter( event => event.type === "content_created" );
+	const updated			= feed.filter( event => event.type === "content_updated" );
+
+	expect( created			).to.have.length( 2 );
+	expect( updated			).to.have.length( 4 );
+	expect( feed.map( event => event.timestamp ) ).to.deep.equal(
+	    feed.map( event => event.timestamp ).sort( (a, b) => a - b )
+	);
+    });
+
+    it("should get carol's (A3) contributions in group", async function () {
+	const result			= await alice_coop_content.get_agent_contributions({
+	    "agent": carol_client.agent_id,
+	    "group_id": group.$id,
+	});
+	log.debug("Agent contributions: %s", json.debug( result ) );
+
+	expect( result			).to.have.length( 1 );
+
+	const targets			= result[0].content_types["content"];
+
+	expect( targets.creates.map( String ) ).to.have.members(
+	    [ c3_addr, c5_addr ].map( addr => String(new HoloHash(addr)) )
+	);
+	expect( targets.updates		).to.have.length( 4 );
+    });
+
+    it("should list a group in the directory and find it by name and tag", async function () {
+	const input			= createGroupInput( [ bobby_client.agent_id ] );
+	input.metadata			= {
+	    "name": "Gardening Club",
+	    "tags": [ "Plants", "outdoors" ],
+	};
+	const group2			= await bobby_coop_content.create_group( input );
+
+	const indexes			= await bobby_coop_content.list_group_in_directory( group2.$id );
+	log.debug("Directory indexes: %s", json.debug( indexes ) );
+
+	expect( indexes			).to.have.length( 4 );
+
+	const by_name			= await alice_coop_content.get_directory_groups({
+	    "name": "garden",
+	});
+	const by_tag			= await alice_coop_content.get_directory_groups({
+	    "tag": "plants",
+	});
+	const by_other_name		= await alice_coop_content.get_directory_groups({
+	    "name": "gardens",
+	});
+
+	expect( by_name.map( g => String(g.$id) ) ).to.include( String(group2.$id) );
+	expect( by_tag.map( g => String(g.$id) ) ).to.include( String(group2.$id) );
+	expect( by_other_name.map( g => String(g.$id) ) ).to.not.include( String(group2.$id) );
+
+	await expect_reject( async () => {
+	    await alice_coop_content.list_group_in_directory( group2.$id );
+	}, "Only admins" );
+
+	await bobby_coop_content.delist_group_from_directory( group2.$id );
+
+	const after_delist		= await alice_coop_content.get_directory_groups();
+
+	expect( after_delist.map( g => String(g.$id) ) ).to.not.include( String(group2.$id) );
+    });
+
+    it("should claim, resolve, transfer, and release a group handle", async function () {
+	const handle			= `acme-docs-${Date.now()}`;
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	await alice_coop_content.claim_group_handle({
+	    "group_id": group.$id,
+	    handle,
+	});
+
+	expect( String(await bobby_coop_content.resolve_group_handle( handle )) ).to.equal( String(group.$id) );
+
+	await expect_reject( async () => {
+	    await bobby_coop_content.claim_group_handle({
+		"group_id": group.$id,
+		handle,
+	    });
+	}, "already claimed" );
+
+	await alice_coop_content.transfer_group_handle({
+	    "group_id": group2.$id,
+	    handle,
+	});
+
+	expect( String(await bobby_coop_content.resolve_group_handle( handle )) ).to.equal( String(group2.$id) );
+
+	await alice_coop_content.release_group_handle( handle );
+
+	await expect_reject( async () => {
+	    await bobby_coop_content.resolve_group_handle( handle );
+	}, "is not claimed" );
+    });
+
+    it("should request to join a group and be approved by an admin", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	await david_coop_content.request_to_join_group({
+	    "group_id": group2.$id,
+	});
+	await david_coop_content.cancel_group_join_request( group2.$id );
+
+	expect( await alice_coop_content.get_group_join_requests( group2.$id ) ).to.have.length( 0 );
+
+	const request_id		= await david_coop_content.request_to_join_group({
+	    "group_id": group2.$id,
+	    "message": "Please let me in",
+	});
+	const requests			= await alice_coop_content.get_group_join_requests( group2.$id );
+
+	expect( requests		).to.have.length( 1 );
+	expect( requests[0].message	).to.equal( "Please let me in" );
+
+	await expect_reject( async () => {
+	    await david_coop_content.approve_group_join_request( request_id );
+	}, "Only admins" );
+
+	const updated			= await alice_coop_content.approve_group_join_request( request_id );
+
+	expect( updated.members.map( String ) ).to.include( String(david_client.agent_id) );
+	expect( await alice_coop_content.get_group_join_requests( group2.$id ) ).to.have.length( 0 );
+    });
+
+    it("should let a member (A4) leave a group", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], david_client.agent_id, carol_client.agent_id )
+	);
+
+	const content_addr		= await david_good_zome.create_content(
+	    createContentInput( group2.$id, group2.$id )
+	);
+
+	const updated			= await david_coop_content.leave_group( group2.$id );
+
+	expect( updated.isMember( david_client.agent_id ) ).to.be.false;
+	expect( updated.isMember( carol_client.agent_id ) ).to.be.true;
+
+	const targets			= (await alice_coop_content.get_all_group_content_targets({
+	    "group_id": group2.$id,
+	})).map( pair => String( pair[1] ) );
+
+	expect( targets			).to.include( String(content_addr) );
+
+	updated.members			= [];
+
+	await expect_reject( async () => {
+	    await carol_coop_content.update_group({
+		"base": updated.$action,
+		"entry": updated,
+	    });
+	}, "members can only remove themselves" );
+    });
+
+    it("should create and update content in batches with per-item results", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+	const inputs			= [ 1, 2, 3 ].map( () => createContentInput( group2.$id, group2.$id ) );
+
+	const created			= await alice_good_zome.create_contents( inputs );
+
+	expect( created			).to.have.length( 3 );
+	expect( created.every( item => item.ok ) ).to.be.true;
+
+	const updated			= await alice_good_zome.update_contents(
+	    created.map( (item, i) => ({
+		"base": item.ok,
+		"entry": Object.assign( {}, inputs[i], { "text": "updated" } ),
+	    }))
+	);
+
+	expect( updated.every( item => item.ok ) ).to.be.true;
+
+	const results			= await alice_coop_content.create_content_update_links([
+	    {
+		"group_id": group2.$id,
+		"content_id": created[0].ok,
+		"content_prev": created[0].ok,
+		"content_next": updated[0].ok,
+	    },
+	    {
+		"group_id": group2.$id,
+		"content_id": created[1].ok,
+		"content_prev": created[0].ok,
+		"content_next": updated[1].ok,
+	    },
+	]);
+
+	expect( results[0].ok		).to.exist;
+	expect( results[1].err.message	).to.have.string( "is not the root of the previous revision" );
+    });
+
+    it("should return the existing link when a content link is retried", async function () {
+	const input			= {
+	    "group_id": group.$id,
+	    "content_target": c5_addr,
+	    "content_type": "content",
+	};
+	const first			= await carol_coop_content.create_content_link( input );
+	const second			= await carol_coop_content.create_content_link( input );
+
+	expect( String(second)		).to.equal( String(first) );
+
+	const contributions		= await alice_coop_content.get_agent_contributions({
+	    "agent": carol_client.agent_id,
+	    "group_id": group.$id,
+	});
+	const creates			= contributions[0].content_types["content"].creates.map( String );
+
+	expect( creates.filter( addr => addr === String(new HoloHash(c5_addr)) ) ).to.have.length( 1 );
+    });
+
+    it("should report and continue the archive copy for a removed member", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], bobby_client.agent_id )
+	);
+
+	await bobby_good_zome.create_content( createContentInput( group2.$id, group2.$id ) );
+	await bobby_good_zome.create_content( createContentInput( group2.$id, group2.$id ) );
+
+	group2.members			= [];
+
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	});
+	const status			= await alice_coop_content.get_archive_status( updated.$action );
+	log.debug("Archive status: %s", json.debug( status ) );
+
+	expect( status			).to.have.length( 1 );
+	expect( status[0].total		).to.equal( 2 );
+	expect( status[0].copied	).to.equal( 2 );
+
+	const progress			= await alice_coop_content.continue_archive({
+	    "group_rev": updated.$action,
+	});
+
+	expect( progress[0].copied	).to.equal( progress[0].total );
+    });
+
+    it("should audit the group and find no problems", async function () {
+	const audit			= await alice_coop_content.audit_group( group.$id );
+	log.debug("Group audit: %s", json.debug( audit ) );
+
+	expect( audit.revisions		).to.have.length( 3 );
+
+	for ( let revision of audit.revisions ) {
+	    expect( revision.missing_auth_links		).to.have.length( 0 );
+	    expect( revision.missing_archive_links	).to.have.length( 0 );
+	    expect( revision.incomplete_archives	).to.have.length( 0 );
+	    expect( revision.orphaned_anchors		).to.have.length( 0 );
+	}
+
+	const created			= await alice_coop_content.repair_group( group.$id );
+
+	expect( created			).to.have.length( 0 );
+
+	await expect_reject( async () => {
+	    await carol_coop_content.repair_group( group.$id );
+	}, "Only admins" );
+    });
+
+    it("should detect and merge concurrent group revisions", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id, bobby_client.agent_id ] )
+	);
+	const base			= group2.$action;
+
+	group2.members			= [ carol_client.agent_id ];
+	await alice_coop_content.update_group({
+	    base,
+	    "entry": group2,
+	});
+
+	group2.members			= [ david_client.agent_id ];
+	await bobby_coop_content.update_group({
+	    base,
+	    "entry": group2,
+	});
+
+	const forks			= await alice_coop_content.get_group_forks( group2.$id );
+
+	expect( forks			).to.have.length( 1 );
+
+	const forked			= await alice_client.call( DNA_NAME, COOP_ZOME, "get_group", group2.$id );
+
+	expect( forked.forks		).to.have.length( 1 );
+
+	const merged			= await alice_coop_content.merge_group_forks( group2.$id );
+
+	expect( merged.isMember( carol_client.agent_id ) ).to.be.true;
+	expect( merged.isMember( david_client.agent_id ) ).to.be.true;
+	expect( await alice_coop_content.get_group_forks( group2.$id ) ).to.have.length( 0 );
+    });
+
+    it("should plan a group update and reject a stale update", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], carol_client.agent_id )
+	);
+	const base			= group2.$action;
+
+	group2.members			= [ david_client.agent_id ];
+	const plan			= await alice_coop_content.plan_group_update({
+	    base,
+	    "entry": group2,
+	});
+	log.debug("Group update plan: %s", json.debug( plan ) );
+
+	expect( plan.contributors_diff.added	).to.have.length( 1 );
+	expect( plan.contributors_diff.removed	).to.have.length( 1 );
+	expect( plan.steps.map( step => step.type ) ).to.have.members([
+	    "archive_member", "add_member", "keep_member",
+	]);
+	expect( String(plan.latest)	).to.equal( String(base) );
+
+	await alice_coop_content.update_group({
+	    base,
+	    "entry": group2,
+	    "require_latest": true,
+	});
+
+	await expect_reject( async () => {
+	    await alice_coop_content.update_group({
+		base,
+		"entry": group2,
+		"require_latest": true,
+	    });
+	}, "StaleRevision" );
+    });
+
+    it("should get group statistics", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+	const inputs			= [ 1, 2 ].map( () => createContentInput( group2.$id, group2.$id ) );
+
+	await alice_good_zome.create_contents( inputs );
+
+	const stats			= await alice_good_zome.get_group_stats( group2.$id );
+	log.debug("Group stats: %s", json.debug( stats ) );
+
+	expect( stats.revisions		).to.equal( 1 );
+	expect( stats.content_types	).to.deep.equal({ "content": 2 });
+	expect( stats.contributors	).to.have.length( 1 );
+	expect( stats.contributors[0].content_types ).to.deep.equal({ "content": 2 });
+	expect( stats.archived		).to.have.length( 0 );
+
+	const direct			= await alice_coop_content.get_group_stats( group2.$id );
+
+	expect( String(direct.group_id)	).to.equal( String(group2.$id) );
+    });
+
+    it("should only allow declared content types", async function () {
+	const input			= createGroupInput( [ alice_client.agent_id ] );
+	input.content_types		= [{
+	    "name": "content",
+	    "schema": "content/v1",
+	}];
+	const group2			= await alice_coop_content.create_group( input );
+
+	await alice_good_zome.create_content( createContentInput( group2.$id, group2.$id ) );
+
+	await expect_reject( async () => {
+	    await alice_good_zome.create_comment( createCommentInput( group2.$id, group2.$id ) );
+	}, "Content type 'comment' is not declared" );
+
+	const content_types		= await alice_coop_content.get_group_content_types( group2.$id );
+	log.debug("Group content types: %s", json.debug( content_types ) );
+
+	expect( content_types		).to.deep.equal([{
+	    "name": "content",
+	    "schema": "content/v1",
+	    "declared": true,
+	    "count": 1,
+	}]);
+
+	await expect_reject( async () => {
+	    const bad_input		= createGroupInput( [ alice_client.agent_id ] );
+	    bad_input.content_types	= [{
+		"name": "content#v1",
+		"schema": null,
+	    }];
+
+	    await alice_coop_content.create_group( bad_input );
+	}, "Content type cannot contain" );
+    });
+
+    it("should check content types declared by a later group revision", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	const entry			= createGroupInput( [ alice_client.agent_id ] );
+	entry.content_types		= [{
+	    "name": "content",
+	    "schema": null,
+	}];
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    entry,
+	});
+
+	await alice_good_zome.create_content( createContentInput( group2.$id, group2.$id ) );
+
+	await expect_reject( async () => {
+	    await alice_client.call( DNA_NAME, EVIL_ZOME, "undeclared_content_link", {
+		"group_id": group2.$id,
+		"group_rev": updated.$action,
+		"content_type": "comnent",
+		"target": new ActionHash( crypto.randomBytes(32) ),
+	    });
+	}, "UndeclaredContentType" );
+    });
+
+    it("should build a content tree from comment parents", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+	const post_id			= await alice_good_zome.create_content(
+	    createContentInput( group2.$id, group2.$id )
+	);
+	const reply_id			= await alice_good_zome.create_comment(
+	    createCommentInput( group2.$id, group2.$id, { "parent_comment": post_id } )
+	);
+	await alice_good_zome.create_comment(
+	    createCommentInput( group2.$id, group2.$id, { "parent_comment": reply_id } )
+	);
+	await alice_good_zome.create_comment(
+	    createCommentInput( group2.$id, group2.$id, { "parent_comment": post_id } )
+	);
+
+	const tree			= await alice_coop_content.get_group_content_tree({
+	    "group_id": group2.$id,
+	    "content_id": post_id,
+	    "content_type": "comment",
+	});
+	log.debug("Content tree: %s", json.debug( tree ) );
+
+	expect( String(tree.id)		).to.equal( String(post_id) );
+	expect( tree.children		).to.have.length( 2 );
+	expect( tree.truncated		).to.be.false;
+
+	const reply			= tree.children.find( node => String(node.id) === String(reply_id) );
+
+	expect( reply.children		).to.have.length( 1 );
+
+	const limited			= await alice_coop_content.get_group_content_tree({
+	    "group_id": group2.$id,
+	    "content_id": post_id,
+	    "content_type": "comment",
+	    "max_depth": 1,
+	    "max_breadth": 1,
+	});
+
+	expect( limited.children	).to.have.length( 1 );
+	expect( limited.truncated	).to.be.true;
+	expect( limited.children[0].children ).to.have.length( 0 );
+    });
+
+    it("should explain content resolution and find no divergence", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], carol_client.agent_id )
+	);
+	const content			= createContentInput( group2.$id, group2.$id );
+	const content_id		= await alice_good_zome.create_content( content );
+	const update_id			= await carol_good_zome.update_content({
+	    "base": content_id,
+	    "entry": Object.assign( {}, content, { "text": "(updated by carol)" } ),
+	});
+
+	const shortcuts			= await alice_coop_content.explain_group_content_evolutions({
+	    "group_id": group2.$id,
+	    "content_id": content_id,
+	});
+	log.debug("Shortcut resolution: %s", json.debug( shortcuts ) );
+
+	expect( shortcuts.evolutions	).to.have.length( 2 );
+	expect( shortcuts.steps.map( step => step.type ) ).to.include.members([
+	    "contribution_link", "update_link",
+	]);
+
+	const full_trace		= await alice_coop_content.explain_group_content_evolutions({
+	    "group_id": group2.$id,
+	    "content_id": content_id,
+	    "full_trace": true,
+	});
+	log.debug("Full-trace resolution: %s", json.debug( full_trace ) );
+
+	expect( String(full_trace.evolutions[1]) ).to.equal( String(update_id) );
+	expect( full_trace.steps.find( step => step.type === "update" ).reason ).to.equal( "contributor" );
+
+	// Carol's update is only accepted through her archive after she is removed
+	group2.members			= [];
+	await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	});
+
+	const archived			= await alice_coop_content.explain_group_content_evolutions({
+	    "group_id": group2.$id,
+	    "content_id": content_id,
+	    "full_trace": true,
+	});
+
+	expect( archived.evolutions	).to.have.length( 2 );
+	expect( archived.steps.find( step => step.type === "update" ).reason ).to.equal( "archived_exception" );
+
+	const divergences		= await alice_coop_content.check_group_content_consistency({
+	    "group_id": group2.$id,
+	    "content_type": null,
+	    "content_base": null,
+	    "full_trace": null,
+	});
+
+	expect( divergences		).to.have.length( 0 );
+    });
+
+    it("should list group revisions with diffs and memos", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], carol_client.agent_id )
+	);
+
+	group2.members			= [ david_client.agent_id ];
+	group2.metadata.topic		= "history";
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	    "memo": "Swap carol for david",
+	});
+
+	const history			= await alice_coop_content.get_group_history( group2.$id );
+	log.debug("Group history: %s", json.debug( history ) );
+
+	expect( history			).to.have.length( 2 );
+	expect( history[0].previous	).to.be.null;
+	expect( history[0].contributors_diff.added ).to.have.length( 2 );
+	expect( String(history[1].group_rev) ).to.equal( String(updated.$action) );
+	expect( history[1].contributors_diff.added ).to.have.length( 1 );
+	expect( history[1].contributors_diff.removed ).to.have.length( 1 );
+	expect( history[1].metadata_changes ).to.deep.equal([{
+	    "key": "topic",
+	    "previous": null,
+	    "current": "history",
+	}]);
+	expect( history[1].memo		).to.equal( "Swap carol for david" );
+
+	await expect_reject( async () => {
+	    await carol_client.call( DNA_NAME, EVIL_ZOME, "invalid_group_memo_link", {
+		"group_rev": updated.$action,
+		"group_id": group2.$id,
+	    });
+	}, "can only be made by the author of the group revision" );
+    });
+
+    it("should return revision context with group entities", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	group2.members			= [ carol_client.agent_id ];
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	});
+
+	const latest			= await alice_client.call( DNA_NAME, COOP_ZOME, "get_group", group2.$id );
+	log.debug("Group entity: %s", json.debug( latest ) );
+
+	expect( latest.revision		).to.equal( 1 );
+	expect( latest.is_latest	).to.be.true;
+	expect( latest.prior_revisions	).to.have.length( 1 );
+	expect( String(new ActionHash( latest.prior_revisions[0] )) ).to.equal( String(group2.$id) );
+	expect( String(new AgentPubKey( latest.author )) ).to.equal( String(alice_client.agent_id) );
+
+	const original			= await alice_client.call( DNA_NAME, COOP_ZOME, "get_group_as_of", {
+	    "group_id": group2.$id,
+	    "as_of": { "revision": group2.$id },
+	});
+
+	expect( original.revision	).to.equal( 0 );
+	expect( original.is_latest	).to.be.false;
+	expect( String(new ActionHash( original.action )) ).not.to.equal( String(updated.$action) );
+    });
+
+    it("should return typed errors that the SDK can decode", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	group2.members			= [ carol_client.agent_id ];
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	});
+
+	await expect_reject( async () => {
+	    await alice_client.call( DNA_NAME, COOP_ZOME, "get_group", updated.$action );
+	}, "NotAnId" );
+
+	const error			= await alice_good_zome.get_group_error( updated.$action );
+	log.debug("Decoded error: %s", json.debug( error ) );
+
+	expect( error.type		).to.equal( "not_an_id" );
+	expect( String(new ActionHash( error.address )) ).to.equal( String(updated.$action) );
+
+	expect(
+	    await alice_good_zome.get_group_error( group2.$id )
+	).to.be.null;
+    });
+
+    it("should verify group membership proofs", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ] )
+	);
+
+	group2.members			= [ carol_client.agent_id ];
+	const updated			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    "entry": group2,
+	});
+
+	const proof			= await alice_coop_content.get_group_membership_proof( updated.$action );
+	log.debug("Membership proof: %s", json.debug( proof ) );
+
+	expect( proof.revisions		).to.have.length( 2 );
+
+	const verified			= await alice_good_zome.get_verified_group([
+	    group2.$id, updated.$action,
+	]);
+
+	expect( verified.members	).to.have.length( 1 );
+	expect( String(new AgentPubKey( verified.members[0] )) ).to.equal( String(carol_client.agent_id) );
+
+	await expect_reject( async () => {
+	    await alice_good_zome.get_verified_group([
+		updated.$action, updated.$action,
+	    ]);
+	}, "NotARevision" );
+    });
+
+    it("should lock content and refuse updates from other agents", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], carol_client.agent_id, david_client.agent_id )
+	);
+	const content			= createContentInput( group2.$id, group2.$id );
+	const content_addr		= await carol_good_zome.create_content( content );
+
+	const lock			= await carol_coop_content.lock_content({
+	    "group_id": group2.$id,
+	    "content_id": content_addr,
+	    "duration": 60,
+	});
+	log.debug("Content lock: %s", json.debug( lock ) );
+
+	expect( String(lock.holder)	).to.equal( String(carol_client.agent_id) );
+
+	{
+	    const current		= await david_coop_content.get_content_lock({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+	    });
+
+	    expect( String(current.id)	).to.equal( String(lock.id) );
+	}
+
+	await expect_reject( async () => {
+	    await david_coop_content.lock_content({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+	    });
+	}, "ContentLocked" );
+
+	await expect_reject( async () => {
+	    await david_good_zome.update_content({
+		"base": content_addr,
+		"entry": Object.assign( {}, content, {
+		    "text":	"(updated) " + faker.lorem.sentence(),
+		}),
+		"respect_locks": true,
+	    });
+	}, "ContentLocked" );
+
+	await expect_reject( async () => {
+	    await david_coop_content.break_content_lock({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+	    });
+	}, "NotAnAdmin" );
+
+	await carol_good_zome.update_content({
+	    "base": content_addr,
+	    "entry": Object.assign( {}, content, {
+		"text":	"(updated) " + faker.lorem.sentence(),
+	    }),
+	    "respect_locks": true,
+	});
+
+	const broken			= await alice_coop_content.break_content_lock({
+	    "group_id": group2.$id,
+	    "content_id": content_addr,
+	});
+
+	expect( broken			).to.have.length( 1 );
+
+	const current			= await alice_coop_content.get_content_lock({
+	    "group_id": group2.$id,
+	    "content_id": content_addr,
+	});
+
+	expect( current			).to.be.null;
+    });
+
+    it("should keep member updates as proposals until reviewed", async function () {
+	const group2			= await alice_coop_content.create_group(
+	    createGroupInput( [ alice_client.agent_id ], carol_client.agent_id, david_client.agent_id )
+	);
+
+	// Review is turned on by a later revision
+	const entry			= createGroupInput(
+	    [ alice_client.agent_id ], carol_client.agent_id, david_client.agent_id
+	);
+	entry.reviewers			= [ david_client.agent_id ];
+	const reviewed			= await alice_coop_content.update_group({
+	    "base": group2.$action,
+	    entry,
+	});
+
+	const content			= createContentInput( group2.$id, group2.$id );
+	const content_addr		= await carol_good_zome.create_content( content );
+	const updated_text		= "(updated) " + faker.lorem.sentence();
+
+	await carol_good_zome.update_content({
+	    "base": content_addr,
+	    "entry": Object.assign( {}, content, {
+		"text":	updated_text,
+	    }),
+	});
+
+	const proposals			= await alice_coop_content.get_content_proposals({
+	    "group_id": group2.$id,
+	});
+	log.debug("Content proposals: %s", json.debug( proposals ) );
+
+	expect( proposals		).to.have.length( 1 );
+	expect( String(proposals[0].author) ).to.equal( String(carol_client.agent_id) );
+
+	await expect_reject( async () => {
+	    await carol_client.call( DNA_NAME, EVIL_ZOME, "unreviewed_update_link", {
+		"group_id": group2.$id,
+		"group_rev": reviewed.$action,
+		"content_id": content_addr,
+		"content_next": proposals[0].content_next,
+	    });
+	}, "NotAReviewer" );
+
+	{
+	    const approved		= await alice_good_zome.get_content({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+	    });
+	    const proposed		= await alice_good_zome.get_content({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+		"include_proposals": true,
+	    });
+
+	    expect( approved.text	).to.equal( content.text );
+	    expect( proposed.text	).to.equal( updated_text );
+	}
+
+	await expect_reject( async () => {
+	    await carol_coop_content.approve_content_proposal( proposals[0].id );
+	}, "NotAReviewer" );
+
+	await david_coop_content.approve_content_proposal( proposals[0].id );
+
+	{
+	    const approved		= await alice_good_zome.get_content({
+		"group_id": group2.$id,
+		"content_id": content_addr,
+	    });
+
+	    expect( approved.text	).to.equal( updated_text );
+	}
+
+	const remaining			= await alice_coop_content.get_content_proposals({
+	    "group_id": group2.$id,
+	});
+
+	expect( remaining		).to.have.length( 0 );
+    });
+
+    it("should commit a membership proposal once the quorum approves", async function () {
+	const input			= createGroupInput(
+	    [ alice_client.agent_id ], carol_client.agent_id, david_client.agent_id
+	);
+	input.governance_quorum		= 2;
+	const group2			= await alice_coop_content.create_group( input );
+
+	const proposal			= await carol_coop_content.propose_group_change({
+	    "group_id": group2.$id,
+	    "changes": {
+		"add_members": [ bobby_client.agent_id ],
+		"metadata": {
+		    "topic": "governance",
+		},
+	    },
+	    "description": "Invite bobby",
+	});
+	log.debug("Governance proposal: %s", json.debug( proposal ) );
+
+	expect( proposal.quorum		).to.equal( 2 );
+	expect( proposal.approvals	).to.have.length( 0 );
+
+	await expect_reject( async () => {
+	    await alice_coop_content.commit_governance_proposal( proposal.id );
+	}, "QuorumNotReached" );
+
+	await expect_reject( async () => {
+	    await bobby_coop_content.vote_on_governance_proposal({
+		"proposal_id": proposal.id,
+		"approve": true,
+	    });
+	}, "NotAContributor" );
+
+	await carol_coop_content.vote_on_governance_proposal({
+	    "proposal_id": proposal.id,
+	    "approve": true,
+	});
+
+	await expect_reject( async () => {
+	    await carol_coop_content.vote_on_governance_proposal({
+		"proposal_id": proposal.id,
+		"approve": false,
+	    });
+	}, "AlreadyVoted" );
+	await david_coop_content.vote_on_governance_proposal({
+	    "proposal_id": proposal.id,
+	    "approve": true,
+	});
+
+	{
+	    const status		= await alice_coop_content.get_governance_proposal( proposal.id );
+
+	    expect( status.approvals	).to.have.length( 2 );
+	    expect( status.rejections	).to.have.length( 0 );
+	}
+
+	await expect_reject( async () => {
+	    await carol_coop_content.commit_governance_proposal( proposal.id );
+	}, "NotAnAdmin" );
+
+	const updated			= await alice_coop_content.commit_governance_proposal( proposal.id );
+
+	expect( updated.members.map( String ) ).to.include( String(bobby_client.agent_id) );
+	expect( updated.metadata.topic	).to.equal( "governance" );
+
+	const proposals			= await alice_coop_content.get_group_governance_proposals( group2.$id );
+
+	expect( proposals		).to.have.length( 1 );
+	expect( String(proposals[0].committed) ).to.equal( String(updated.$action) );
+
+	await expect_reject( async () => {
+	    await alice_coop_content.commit_governance_proposal( proposal.id );
+	}, "AlreadyCommitted" );
+    });
+
     linearSuite( "Phase 3 - Checks", phase3_checks_tests );
 }
 
@@ -752,7 +1669,7 @@ function phase3_checks_tests () {
 		},
 		c3_addr,
 	    ]);
-	}, "can only be deleted by an admin" );
+	}, "NotAContributor" );
     });
 
 }
@@ -114,6 +114,17 @@ export const BasicUsageZomelet	        = new Zomelet({
 	return new ActionHash( result );
     },
 
+    async create_contents ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( item => item.ok ? { "ok": new ActionHash( item.ok ) } : item );
+    },
+    async update_contents ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( item => item.ok ? { "ok": new ActionHash( item.ok ) } : item );
+    },
+
     //
     // Comment
     //
@@ -131,6 +142,15 @@ export const BasicUsageZomelet	        = new Zomelet({
 
 	return Group( result );
     },
+    async get_group_stats ( input ) {
+	return await this.call( input );
+    },
+    async get_group_error ( input ) {
+	return await this.call( input );
+    },
+    async get_verified_group ( input ) {
+	return await this.call( input );
+    },
     async get_group_content ( input ) {
 	const result			= await this.call( input );
 
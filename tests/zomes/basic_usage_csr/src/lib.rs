@@ -8,6 +8,8 @@ pub use coop_content_sdk::hdk_extensions;
 use hdk::prelude::*;
 use hdi_extensions::{
     ScopedTypeConnector,
+    // Macros
+    guest_error,
 };
 use hdk_extensions::{
     must_get,
@@ -23,12 +25,22 @@ use coop_content_sdk::{
     GroupEntry,
     GetGroupContentInput,
     GetAllGroupContentInput,
+    GroupStats,
+    CoopContentError,
+    ZomeCallTarget,
+    BatchResult,
+    RegisterContributionMacroInput,
+    RegisterContributionUpdateMacroInput,
     // Macros
     create_group, get_group, update_group,
     get_group_content_latest,
     get_all_group_content_latest,
+    get_group_stats,
+    get_group_membership_proof,
     register_content_to_group,
     register_content_update_to_group,
+    register_contents_to_group,
+    register_content_updates_to_group,
 };
 
 
@@ -49,9 +61,9 @@ fn whoami(_: ()) -> ExternResult<AgentInfo> {
 #[hdk_extern]
 pub fn create_group(group: GroupEntry) -> ExternResult<ActionHash> {
     debug!("Creating new group entry: {:#?}", group );
-    let action_hash = create_group!( group )?;
+    let group = create_group!( group )?;
 
-    Ok( action_hash )
+    Ok( group.action )
 }
 
 
@@ -60,19 +72,49 @@ pub fn get_group(id: ActionHash) -> ExternResult<GroupEntry> {
     debug!("Creating new group entry: {:#?}", id );
     let group = get_group!( id )?;
 
-    Ok( group )
+    Ok( group.content )
+}
+
+
+#[hdk_extern]
+pub fn get_group_error(id: ActionHash) -> ExternResult<Option<CoopContentError>> {
+    debug!("Get group error: {:#?}", id );
+    Ok(
+        get_group!( id ).err()
+            .and_then( |err| CoopContentError::try_from( &err ).ok() )
+    )
+}
+
+
+#[hdk_extern]
+pub fn get_verified_group(group_ref: (ActionHash, ActionHash)) -> ExternResult<GroupEntry> {
+    debug!("Get verified group: {:#?}", group_ref );
+    let proof = get_group_membership_proof!(
+        target = ZomeCallTarget::from( CallTargetCell::Local );
+        group_ref.1.to_owned()
+    )?;
+
+    proof.verify( &group_ref.0, &group_ref.1 )
+        .map_err( |message| guest_error!(message) )
 }
 
 
 #[hdk_extern]
 pub fn update_group(input: UpdateEntryInput<GroupEntry>) -> ExternResult<ActionHash> {
     debug!("Update group: {:#?}", input );
-    let action_hash = update_group!({
+    let group = update_group!({
         base: input.base,
         entry: input.entry,
     })?;
 
-    Ok( action_hash )
+    Ok( group.action )
+}
+
+
+#[hdk_extern]
+pub fn get_group_stats(group_id: ActionHash) -> ExternResult<GroupStats> {
+    debug!("Get group stats: {}", group_id );
+    get_group_stats!( group_id )
 }
 
 
@@ -82,6 +124,8 @@ pub fn get_content(input: GetGroupContentInput) -> ExternResult<ContentEntry> {
     let latest_addr = get_group_content_latest!({
         group_id: input.group_id,
         content_id: input.content_id,
+        as_of: input.as_of,
+        include_proposals: input.include_proposals,
     })?;
     let record = must_get( &latest_addr )?;
 
@@ -115,6 +159,8 @@ pub fn get_group_content(input: GetAllGroupContentInput) -> ExternResult<Vec<Ent
         group_id: input.group_id,
         content_type: input.content_type,
         content_base: input.content_base,
+        as_of: input.as_of,
+        include_proposals: input.include_proposals,
     })?.into_iter()
         .filter_map(|(origin, latest)| {
             let origin_addr = origin.into_action_hash()?;
@@ -152,6 +198,8 @@ pub fn create_content(content: ContentEntry) -> ExternResult<ActionHash> {
 pub struct UpdateInput {
     base: ActionHash,
     entry: ContentEntry,
+    #[serde(default)]
+    respect_locks: bool,
 }
 
 #[hdk_extern]
@@ -163,12 +211,52 @@ pub fn update_content(input: UpdateInput) -> ExternResult<ActionHash> {
     register_content_update_to_group!({
         entry: input.entry,
         target: action_hash.clone(),
+        respect_locks: input.respect_locks,
     })?;
 
     Ok( action_hash )
 }
 
 
+#[hdk_extern]
+pub fn create_contents(contents: Vec<ContentEntry>) -> ExternResult<Vec<BatchResult<ActionHash>>> {
+    debug!("Creating {} content entries", contents.len() );
+    let mut inputs = vec![];
+
+    for content in contents {
+        let action_hash = create_entry( content.to_input() )?;
+
+        inputs.push( RegisterContributionMacroInput {
+            entry: content,
+            target: action_hash,
+            content_type: String::from("content"),
+            content_base: None,
+        });
+    }
+
+    register_contents_to_group!( inputs )
+}
+
+
+#[hdk_extern]
+pub fn update_contents(updates: Vec<UpdateInput>) -> ExternResult<Vec<BatchResult<ActionHash>>> {
+    debug!("Updating {} content entries", updates.len() );
+    let mut inputs = vec![];
+
+    for input in updates {
+        let action_hash = update_entry( input.base, input.entry.to_input() )?;
+
+        inputs.push( RegisterContributionUpdateMacroInput {
+            entry: input.entry,
+            target: action_hash,
+            respect_locks: input.respect_locks,
+        });
+    }
+
+    register_content_updates_to_group!( inputs )
+}
+
+
 #[hdk_extern]
 pub fn create_comment(comment: CommentEntry) -> ExternResult<ActionHash> {
     debug!("Creating new comment entry: {:#?}", comment );
@@ -133,6 +133,26 @@ pub struct InvalidLinkBaseInput {
     target: ActionHash,
 }
 
+#[derive(Clone, Deserialize, Debug)]
+pub struct UndeclaredContentLinkInput {
+    group_id: ActionHash,
+    group_rev: ActionHash,
+    content_type: String,
+    target: ActionHash,
+}
+
+#[hdk_extern]
+pub fn undeclared_content_link(input: UndeclaredContentLinkInput) -> ExternResult<()> {
+    debug!("UndeclaredContentLinkInput: {:#?}", input );
+    let anchor = ContributionsAnchorEntry( input.group_id, agent_info()?.agent_initial_pubkey );
+    let tag = format!("#{}#:\0{}", input.content_type, input.group_rev );
+
+    create_link( hash_entry( &anchor )?, input.target, LinkTypes::Contribution, tag.into_bytes() )?;
+
+    Ok(())
+}
+
+
 #[hdk_extern]
 pub fn invalid_content_link_base(input: InvalidLinkBaseInput) -> ExternResult<()> {
     debug!("InvalidLinkBaseInput: {:#?}", input );
@@ -158,3 +178,38 @@ pub fn delete_group(addr: ActionHash) -> ExternResult<()> {
 
     Ok(())
 }
+
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct InvalidGroupMemoInput {
+    group_rev: ActionHash,
+    group_id: ActionHash,
+}
+
+#[hdk_extern]
+pub fn invalid_group_memo_link(input: InvalidGroupMemoInput) -> ExternResult<()> {
+    debug!("InvalidGroupMemoInput: {:#?}", input );
+    create_link( input.group_rev, input.group_id, LinkTypes::GroupRevisionMemo, "Not my revision".as_bytes().to_vec() )?;
+
+    Ok(())
+}
+
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct UnreviewedUpdateInput {
+    group_id: ActionHash,
+    group_rev: ActionHash,
+    content_id: ActionHash,
+    content_next: ActionHash,
+}
+
+#[hdk_extern]
+pub fn unreviewed_update_link(input: UnreviewedUpdateInput) -> ExternResult<()> {
+    debug!("UnreviewedUpdateInput: {:#?}", input );
+    let anchor = ContributionsAnchorEntry( input.group_id, agent_info()?.agent_initial_pubkey );
+    let tag = format!("{}:{}\0{}", input.content_id, input.content_id, input.group_rev );
+
+    create_link( hash_entry( &anchor )?, input.content_next, LinkTypes::ContributionUpdate, tag.into_bytes() )?;
+
+    Ok(())
+}
@@ -12,6 +12,31 @@ import {
 }					from './types.js';
 
 
+function content_lock ( lock ) {
+    lock.id				= new ActionHash( lock.id );
+    lock.group_id			= new ActionHash( lock.group_id );
+    lock.content_id			= new AnyLinkableHash( lock.content_id );
+    lock.holder				= new AgentPubKey( lock.holder );
+    lock.group_rev			= new ActionHash( lock.group_rev );
+
+    return lock;
+}
+
+function governance_proposal ( proposal ) {
+    proposal.id				= new ActionHash( proposal.id );
+    proposal.group_id			= new ActionHash( proposal.group_id );
+    proposal.author			= new AgentPubKey( proposal.author );
+    proposal.proposal.group_rev		= new ActionHash( proposal.proposal.group_rev );
+    proposal.approvals			= proposal.approvals.map( agent => new AgentPubKey( agent ) );
+    proposal.rejections			= proposal.rejections.map( agent => new AgentPubKey( agent ) );
+
+    if ( proposal.committed )
+	proposal.committed		= new ActionHash( proposal.committed );
+
+    return proposal;
+}
+
+
 export const CoopContentZomelet		= new Zomelet({
     "whoami": {
 	output ( response ) {
@@ -43,6 +68,11 @@ export const CoopContentZomelet		= new Zomelet({
 
 	return new Group( result, this );
     },
+    async get_group_as_of ( input ) {
+	const result			= await this.call( input );
+
+	return new Group( result, this );
+    },
     async get_my_invites ( input ) {
 	const result			= await this.call( input );
 
@@ -87,6 +117,16 @@ export const CoopContentZomelet		= new Zomelet({
 
 	return new ActionHash( result );
     },
+    async create_content_links ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( item => item.ok ? { "ok": new ActionHash( item.ok ) } : item );
+    },
+    async create_content_update_links ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( item => item.ok ? { "ok": new ActionHash( item.ok ) } : item );
+    },
     async get_group_content_latest ( input ) {
 	const result			= await this.call( input );
 
@@ -107,6 +147,263 @@ export const CoopContentZomelet		= new Zomelet({
             ];
         });
     },
+    async get_group_activity ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( event => Object.assign( event, {
+	    "author":			new AgentPubKey( event.author ),
+	}));
+    },
+    async get_agent_contributions ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( contributions => {
+	    contributions.group_id	= new ActionHash( contributions.group_id );
+
+	    for ( let targets of Object.values( contributions.content_types ) ) {
+		targets.creates		= targets.creates.map( hash => new AnyLinkableHash( hash ) );
+		targets.updates		= targets.updates.map( hash => new AnyLinkableHash( hash ) );
+	    }
+
+	    return contributions;
+	});
+    },
+    async list_group_in_directory ( input ) {
+	return await this.call( input );
+    },
+    async delist_group_from_directory ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async get_directory_groups ( input = {} ) {
+	const result			= await this.call( input );
+
+	return result.map( group => new Group( group, this ) );
+    },
+    async claim_group_handle ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async resolve_group_handle ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async transfer_group_handle ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async release_group_handle ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async request_to_join_group ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async get_group_join_requests ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( request => {
+	    request.id			= new ActionHash( request.id );
+	    request.group_id		= new ActionHash( request.group_id );
+	    request.agent		= new AgentPubKey( request.agent );
+
+	    return request;
+	});
+    },
+    async approve_group_join_request ( input ) {
+	const result			= await this.call( input );
+
+	return new Group( result, this );
+    },
+    async cancel_group_join_request ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async leave_group ( input ) {
+	const result			= await this.call( input );
+
+	return new Group( result, this );
+    },
+    async continue_archive ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( progress => {
+	    progress.agent		= new AgentPubKey( progress.agent );
+	    progress.anchor		= new EntryHash( progress.anchor );
+
+	    return progress;
+	});
+    },
+    async get_archive_status ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( progress => {
+	    progress.agent		= new AgentPubKey( progress.agent );
+	    progress.anchor		= new EntryHash( progress.anchor );
+
+	    return progress;
+	});
+    },
+    async audit_group ( input ) {
+	return await this.call( input );
+    },
+    async repair_group ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async get_group_forks ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async merge_group_forks ( input ) {
+	const result			= await this.call( input );
+
+	return new Group( result, this );
+    },
+    async plan_group_update ( input ) {
+	const result			= await this.call( input );
+
+	result.group_id			= new ActionHash( result.group_id );
+	result.base			= new ActionHash( result.base );
+	result.latest			= new ActionHash( result.latest );
+
+	return result;
+    },
+    async get_group_stats ( input ) {
+	const result			= await this.call( input );
+
+	result.group_id			= new ActionHash( result.group_id );
+
+	return result;
+    },
+    async get_group_content_types ( input ) {
+	return await this.call( input );
+    },
+    async get_group_content_tree ( input ) {
+	const result			= await this.call( input );
+
+	function intoNode ( node ) {
+	    node.id			= new AnyLinkableHash( node.id );
+	    node.latest			= new AnyLinkableHash( node.latest );
+	    node.children		= node.children.map( intoNode );
+
+	    return node;
+	}
+
+	return intoNode( result );
+    },
+    async explain_group_content_evolutions ( input ) {
+	const result			= await this.call( input );
+
+	result.content_id		= new AnyLinkableHash( result.content_id );
+	result.group_rev		= new ActionHash( result.group_rev );
+	result.evolutions		= result.evolutions.map( hash => new AnyLinkableHash( hash ) );
+
+	return result;
+    },
+    async check_group_content_consistency ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( divergence => {
+	    divergence.content_id	= new AnyLinkableHash( divergence.content_id );
+
+	    return divergence;
+	});
+    },
+    async get_group_history ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( revision => {
+	    revision.group_rev		= new ActionHash( revision.group_rev );
+	    revision.previous		= revision.previous ? new ActionHash( revision.previous ) : null;
+	    revision.author		= new AgentPubKey( revision.author );
+
+	    return revision;
+	});
+    },
+    async get_group_membership_proof ( input ) {
+	return await this.call( input );
+    },
+    async lock_content ( input ) {
+	const result			= await this.call( input );
+
+	return content_lock( result );
+    },
+    async get_content_lock ( input ) {
+	const result			= await this.call( input );
+
+	return result ? content_lock( result ) : null;
+    },
+    async unlock_content ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async break_content_lock ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( hash => new ActionHash( hash ) );
+    },
+    async get_content_proposals ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( proposal => {
+	    proposal.id			= new ActionHash( proposal.id );
+	    proposal.group_id		= new ActionHash( proposal.group_id );
+	    proposal.content_id		= new AnyLinkableHash( proposal.content_id );
+	    proposal.content_prev	= new AnyLinkableHash( proposal.content_prev );
+	    proposal.content_next	= new AnyLinkableHash( proposal.content_next );
+	    proposal.author		= new AgentPubKey( proposal.author );
+	    proposal.group_rev		= new ActionHash( proposal.group_rev );
+
+	    return proposal;
+	});
+    },
+    async approve_content_proposal ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async reject_content_proposal ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async propose_group_change ( input ) {
+	const result			= await this.call( input );
+
+	return governance_proposal( result );
+    },
+    async get_group_governance_proposals ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( proposal => governance_proposal( proposal ) );
+    },
+    async get_governance_proposal ( input ) {
+	const result			= await this.call( input );
+
+	return governance_proposal( result );
+    },
+    async vote_on_governance_proposal ( input ) {
+	const result			= await this.call( input );
+
+	return new ActionHash( result );
+    },
+    async commit_governance_proposal ( input ) {
+	const result			= await this.call( input );
+
+	return new Group( result, this );
+    },
     async remove_group_links ( input ) {
 	const result			= await this.call( input );
 
@@ -30,6 +30,13 @@ pub enum EntryTypes {
 
     #[entry_type]
     ArchivedContributionsAnchor(ArchivedContributionsAnchorEntry),
+
+    // Governance
+    #[entry_type]
+    GovernanceProposal(GovernanceProposalEntry),
+
+    #[entry_type]
+    GovernanceVote(GovernanceVoteEntry),
 }
 
 scoped_type_connector!(
@@ -44,6 +51,14 @@ scoped_type_connector!(
     EntryTypesUnit::ArchivedContributionsAnchor,
     EntryTypes::ArchivedContributionsAnchor( ArchivedContributionsAnchorEntry )
 );
+scoped_type_connector!(
+    EntryTypesUnit::GovernanceProposal,
+    EntryTypes::GovernanceProposal( GovernanceProposalEntry )
+);
+scoped_type_connector!(
+    EntryTypesUnit::GovernanceVote,
+    EntryTypes::GovernanceVote( GovernanceVoteEntry )
+);
 
 
 
@@ -56,6 +71,16 @@ pub enum LinkTypes {
     GroupAuthArchive,
     Contribution,
     ContributionUpdate,
+    GroupDirectory,
+    GroupHandle,
+    GroupJoinRequest,
+    GroupMerge,
+    GroupRevisionMemo,
+    ContentLock,
+    ContributionProposal,
+    GroupGovernanceProposal,
+    GovernanceVote,
+    GovernanceCommit,
 }
 
 impl TryFrom<String> for LinkTypes {
@@ -70,6 +95,16 @@ impl TryFrom<String> for LinkTypes {
                 "GroupAuthArchive" => LinkTypes::GroupAuthArchive,
                 "Contribution" => LinkTypes::Contribution,
                 "ContributionUpdate" => LinkTypes::ContributionUpdate,
+                "GroupDirectory" => LinkTypes::GroupDirectory,
+                "GroupHandle" => LinkTypes::GroupHandle,
+                "GroupJoinRequest" => LinkTypes::GroupJoinRequest,
+                "GroupMerge" => LinkTypes::GroupMerge,
+                "GroupRevisionMemo" => LinkTypes::GroupRevisionMemo,
+                "ContentLock" => LinkTypes::ContentLock,
+                "ContributionProposal" => LinkTypes::ContributionProposal,
+                "GroupGovernanceProposal" => LinkTypes::GroupGovernanceProposal,
+                "GovernanceVote" => LinkTypes::GovernanceVote,
+                "GovernanceCommit" => LinkTypes::GovernanceCommit,
                 _ => return Err(guest_error!(format!("Unknown LinkTypes variant: {}", name ))),
             }
         )
@@ -82,9 +117,7 @@ impl<'de> Deserialize<'de> for LinkTypes {
         D: Deserializer<'de>,
     {
         let s: String = Deserialize::deserialize(deserializer)?;
-        Ok(
-            LinkTypes::try_from( s.clone() )
-                .or(Err(serde::de::Error::custom(format!("Unknown LinkTypes variant: {}", s))))?
-        )
+        LinkTypes::try_from( s.clone() )
+            .or(Err(serde::de::Error::custom(format!("Unknown LinkTypes variant: {}", s))))
     }
 }
@@ -2,14 +2,84 @@ use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
+    EntryTypesUnit,
+    GroupEntry,
+    GovernanceProposalEntry,
+    GovernanceVoteEntry,
+    CoopContentError,
+    GOVERNANCE_DESCRIPTION_MAX_BYTES,
 };
 use hdi::prelude::*;
 use hdi_extensions::{
+    trace_origin_root,
+    detect_app_entry_unit,
     // Macros
-    valid, invalid,
+    valid, invalid, guest_error,
 };
 
 
+/// Check that the author is a contributor of a proposal's group revision and that the group has
+/// not been deleted
+fn validate_governance_group(
+    group_rev: &ActionHash,
+    create: &Create,
+) -> ExternResult<GroupEntry> {
+    let group : GroupEntry = must_get_valid_record( group_rev.to_owned() )?.try_into()?;
+
+    if !group.is_contributor( &create.author ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: trace_origin_root( group_rev )?.0,
+            agent: create.author.to_owned(),
+        })?;
+    }
+
+    if group.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id: trace_origin_root( group_rev )?.0,
+        })?;
+    }
+
+    Ok( group )
+}
+
+
+/// Check that the author has not already voted on the same proposal
+///
+/// Votes are final so that a governance commit can count them without knowing about later
+/// activity.
+fn validate_first_vote(
+    vote: &GovernanceVoteEntry,
+    create: &Create,
+) -> ExternResult<()> {
+    let activity = must_get_agent_activity(
+        create.author.to_owned(),
+        ChainFilter::new( create.prev_action.to_owned() ),
+    )?;
+
+    for item in activity {
+        let prev_create = match item.action.action() {
+            Action::Create(prev_create) => prev_create.to_owned(),
+            _ => continue,
+        };
+
+        if !matches!( detect_app_entry_unit( &prev_create ), Ok(EntryTypesUnit::GovernanceVote) ) {
+            continue;
+        }
+
+        let prev_vote : GovernanceVoteEntry = must_get_entry( prev_create.entry_hash )?.content.try_into()?;
+
+        if prev_vote.proposal == vote.proposal {
+            Err(CoopContentError::AlreadyVoted {
+                proposal: vote.proposal.to_owned(),
+                agent: create.author.to_owned(),
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+
 pub fn validation(
     app_entry: EntryTypes,
     create: Create
@@ -17,7 +87,19 @@ pub fn validation(
     match app_entry {
         EntryTypes::Group(group) => {
             if !group.is_admin( &create.author ) {
-                invalid!("The author of a group entry must be an admin of the group".to_string())
+                invalid!(CoopContentError::NotAnAdmin {
+                    group_id: hash_action( Action::Create( create.to_owned() ) )?,
+                    agent: create.author.to_owned(),
+                    operation: "create it".to_string(),
+                }.into())
+            }
+
+            if let Err(message) = group.check_content_types() {
+                invalid!(message)
+            }
+
+            if let Err(message) = group.governance_quorum() {
+                invalid!(message)
             }
 
             valid!()
@@ -28,6 +110,31 @@ pub fn validation(
         EntryTypes::ArchivedContributionsAnchor(_anchor) => {
             valid!()
         },
+        EntryTypes::GovernanceProposal(proposal) => {
+            let group = validate_governance_group( &proposal.group_rev, &create )?;
+
+            if let Err(reason) = proposal.changes.check( &group ) {
+                invalid!(CoopContentError::InvalidGovernanceChanges {
+                    group_id: trace_origin_root( &proposal.group_rev )?.0,
+                    reason,
+                }.into())
+            }
+
+            if proposal.description.as_ref()
+                .is_some_and( |text| text.len() > GOVERNANCE_DESCRIPTION_MAX_BYTES ) {
+                Err(guest_error!(format!("Governance proposal description cannot be larger than {} bytes", GOVERNANCE_DESCRIPTION_MAX_BYTES )))?;
+            }
+
+            valid!()
+        },
+        EntryTypes::GovernanceVote(vote) => {
+            let proposal : GovernanceProposalEntry = must_get_valid_record( vote.proposal.to_owned() )?.try_into()?;
+
+            validate_governance_group( &proposal.group_rev, &create )?;
+            validate_first_vote( &vote, &create )?;
+
+            valid!()
+        },
         // _ => invalid!(format!("Create validation not implemented for entry type: {:#?}", create.entry_type )),
     }
 }
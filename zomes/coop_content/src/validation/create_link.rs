@@ -4,10 +4,22 @@ use crate::{
     holo_hash,
     LinkTypes,
     GroupEntry,
+    GroupHandleTag,
+    ContentLockTag,
+    ContributionProposalTag,
+    GovernanceCommitTag,
+    GovernanceProposalEntry,
+    GovernanceVoteEntry,
     ContributionAnchors,
+    CoopContentError,
+    check_group_handle,
+    group_handle_path,
+    split_contribution_tag,
+    CONTENT_LOCK_MAX_DURATION,
 };
 use hdi::prelude::*;
 use hdi_extensions::{
+    trace_origin,
     trace_origin_root,
     summon_app_entry,
     verify_app_entry_struct,
@@ -18,16 +30,66 @@ use hdi_extensions::{
 use holo_hash::AnyLinkableHashPrimitive;
 
 
+/// The maximum size (in bytes) of a join request message
+const JOIN_REQUEST_MESSAGE_MAX_BYTES : usize = 1024;
+
+/// The maximum size (in bytes) of a group revision memo
+const GROUP_MEMO_MAX_BYTES : usize = 1024;
+
+
+/// Check if a group revision is the given revision or one of its descendants
+fn is_same_or_later_revision(
+    group_rev: &ActionHash,
+    ancestor: &ActionHash,
+) -> ExternResult<bool> {
+    let mut current = group_rev.to_owned();
+
+    loop {
+        if current == *ancestor {
+            return Ok(true);
+        }
+
+        current = match must_get_action( current )?.action() {
+            Action::Update(update) => update.original_action_address.to_owned(),
+            _ => return Ok(false),
+        };
+    }
+}
+
+/// Check if a group revision is the given agent removing themselves from the members list
+fn is_self_removal_revision(
+    record: &Record,
+    agent: &AgentPubKey,
+) -> ExternResult<bool> {
+    let prev_rev = match record.action() {
+        Action::Update(update) if update.author == *agent => update.original_action_address.to_owned(),
+        _ => return Ok(false),
+    };
+    let group : GroupEntry = record.to_owned().try_into()?;
+    let prev_group : GroupEntry = must_get_valid_record( prev_rev )?.try_into()?;
+
+    Ok( !prev_group.is_admin( agent ) && prev_group.is_self_removal( &group, agent ) )
+}
+
 fn validate_content_link_base(
     base: &AnyLinkableHash,
     create: &CreateLink,
 ) -> ExternResult<()> {
-    let anchor : ContributionAnchors = summon_app_entry( &base )?;
+    let anchor : ContributionAnchors = summon_app_entry( base )?;
 
     if anchor.is_archive() {
-        let group : GroupEntry = must_get_valid_record( anchor.group().to_owned() )?.try_into()?;
-        if !group.admins.contains( &create.author ) {
-            Err(guest_error!(format!("Creating a link based on an auth archive anchor can only be made by group admins")))?
+        let group_record = must_get_valid_record( anchor.group().to_owned() )?;
+        // A member who removed themselves can copy their own links into their archive anchor
+        let is_own_leave = anchor.author() == &create.author
+            && is_self_removal_revision( &group_record, &create.author )?;
+        let group : GroupEntry = group_record.try_into()?;
+
+        if !group.admins.contains( &create.author ) && !is_own_leave {
+            Err(CoopContentError::NotAnAdmin {
+                group_id: trace_origin_root( anchor.group() )?.0,
+                agent: create.author.to_owned(),
+                operation: "link content to an archived contributions anchor".to_string(),
+            })?
         }
     } else if anchor.author() != &create.author {
         Err(guest_error!(format!("Creating a link based on an auth anchor can only be made by the matching agent ({})", anchor.author() )))?
@@ -36,26 +98,666 @@ fn validate_content_link_base(
     Ok(())
 }
 
+/// Check that the author of a contribution update link does not need their updates reviewed in
+/// the group revision of its tag
+///
+/// Members who need review must create a contribution proposal link instead, which a reviewer
+/// approves with an update link from their own anchor.  Archive anchors are only written by admins,
+/// so only their tagged revision is checked.
+fn validate_update_review(
+    base: &AnyLinkableHash,
+    tag_rev: Option<&str>,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let anchor : ContributionAnchors = summon_app_entry( base )?;
+    let (_, group) = contribution_tag_group_rev( &anchor, tag_rev, "ContributionUpdate", create )?;
+
+    if anchor.is_archive() {
+        return Ok(());
+    }
+
+    if group.requires_review_for( &create.author ) {
+        Err(CoopContentError::NotAReviewer {
+            group_id: anchor.group().to_owned(),
+            agent: create.author.to_owned(),
+        })?
+    }
+
+    Ok(())
+}
+
+/// Get the group revision that a contribution link tag was created under
+///
+/// The revision must be a revision of the anchor's group that existed when the link was created
+/// and the anchor agent must be one of its contributors.  Archive anchors hold copies of links
+/// from active anchors, so their tags keep the revision of the original link, which must be
+/// earlier than the anchor's (removing) revision.
+fn contribution_tag_group_rev(
+    anchor: &ContributionAnchors,
+    tag_rev: Option<&str>,
+    link_type: &str,
+    create: &CreateLink,
+) -> ExternResult<(ActionHash, GroupEntry)> {
+    let group_rev = match tag_rev.map( |rev| ActionHash::try_from( rev.to_string() ) ) {
+        Some(Ok(addr)) => addr,
+        Some(Err(err)) => Err(CoopContentError::MalformedTag {
+            link_type: link_type.to_string(),
+            reason: format!("invalid group revision: {:?}", err ),
+        })?,
+        None => Err(CoopContentError::MalformedTag {
+            link_type: link_type.to_string(),
+            reason: "missing group revision".to_string(),
+        })?,
+    };
+    let group_id = trace_origin_root( anchor.group() )?.0;
+
+    if trace_origin_root( &group_rev )?.0 != group_id {
+        Err(CoopContentError::NotARevision {
+            id: group_id.to_owned(),
+            address: group_rev.to_owned(),
+        })?
+    }
+
+    let record = must_get_valid_record( group_rev.to_owned() )?;
+
+    if record.action().timestamp() > create.timestamp {
+        Err(guest_error!(format!("{} link cannot be tagged with a group revision made after it ({})", link_type, group_rev )))?
+    }
+
+    if anchor.is_archive()
+        && record.action().timestamp() > must_get_action( anchor.group().to_owned() )?.action().timestamp() {
+        Err(guest_error!(format!("Archived {} link must be tagged with a group revision made before the archive anchor's revision ({})", link_type, anchor.group() )))?
+    }
+
+    let group : GroupEntry = record.try_into()?;
+
+    if !group.is_contributor( anchor.author() ) {
+        Err(CoopContentError::NotAContributor {
+            group_id,
+            agent: anchor.author().to_owned(),
+        })?
+    }
+
+    Ok( (group_rev, group) )
+}
+
+/// Check the content type of a contribution link against the declared content types of the group
+/// revision in its tag
+fn validate_contribution_type(
+    base: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let anchor : ContributionAnchors = summon_app_entry( base )?;
+    let tag_str = match String::from_utf8( tag.into_inner() ) {
+        Ok(text) => text,
+        Err(err) => Err(CoopContentError::MalformedTag {
+            link_type: "Contribution".to_string(),
+            reason: format!("must be a UTF8 string: {}", err ),
+        })?,
+    };
+    let (type_tag, tag_rev) = split_contribution_tag( &tag_str );
+    let content_type = match type_tag.strip_prefix("#").and_then( |tag| tag.split_once("#:") ) {
+        Some((content_type, _)) => content_type,
+        None => Err(CoopContentError::MalformedTag {
+            link_type: "Contribution".to_string(),
+            reason: format!("expected '#<content type>#:<content base>'; not {:?}", type_tag ),
+        })?,
+    };
+    let (group_rev, group) = contribution_tag_group_rev( &anchor, tag_rev, "Contribution", create )?;
+
+    if !group.allows_content_type( content_type ) {
+        Err(CoopContentError::UndeclaredContentType {
+            content_type: content_type.to_string(),
+            group_rev,
+        })?
+    }
+
+    Ok(())
+}
+
 fn validate_anchor_link_base(
     base: &AnyLinkableHash,
     target: &AnyLinkableHash,
+    link_type: &LinkTypes,
     create: &CreateLink,
 ) -> ExternResult<()> {
     let group : GroupEntry = summon_app_entry( base )?;
+    let group_rev = match base.clone().into_action_hash() {
+        Some(hash) => hash,
+        None => Err(guest_error!(format!("Group auth link base must be a group revision; not '{}'", base )))?,
+    };
+    let anchor : ContributionAnchors = summon_app_entry( target )?;
 
     if !group.is_admin( &create.author ) {
-        Err(guest_error!("The author of a group auth link must be an admin of the base group".to_string()))?;
-    }
+        // A member removing themselves can only link their own archive anchor for that revision
+        let is_own_leave_archive = *link_type == LinkTypes::GroupAuthArchive
+            && anchor.is_archive()
+            && anchor.group() == &group_rev
+            && anchor.author() == &create.author
+            && must_get_action( group_rev.to_owned() )?.action().author() == &create.author;
 
-    let anchor : ContributionAnchors = summon_app_entry( target )?;
+        if !is_own_leave_archive {
+            Err(CoopContentError::NotAnAdmin {
+                group_id: trace_origin_root( &group_rev )?.0,
+                agent: create.author.to_owned(),
+                operation: "link contributions anchors to a group revision".to_string(),
+            })?;
+        }
+    }
 
     if !anchor.is_archive() && !group.is_contributor( anchor.author() ) {
-        Err(guest_error!(format!("Links to a contributions anchor must match a contributor in the group base")))?;
+        Err(CoopContentError::NotAContributor {
+            group_id: trace_origin_root( &group_rev )?.0,
+            agent: anchor.author().to_owned(),
+        })?;
+    }
+
+    Ok(())
+}
+
+fn validate_directory_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let group_rev = match ActionHash::from_raw_39( tag.into_inner() ) {
+        Ok(addr) => addr,
+        Err(err) => Err(CoopContentError::MalformedTag {
+            link_type: "GroupDirectory".to_string(),
+            reason: format!("must be a group revision: {}", err ),
+        })?,
+    };
+    let group_id = trace_origin_root( &group_rev )?.0;
+
+    if *target != group_id.clone().into() {
+        Err(guest_error!(format!("Group directory link target must be the ID of the tagged group revision ({})", group_id )))?;
+    }
+
+    let group : GroupEntry = must_get_valid_record( group_rev.to_owned() )?.try_into()?;
+
+    if !group.is_admin( &create.author ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: create.author.to_owned(),
+            operation: "list it in the directory".to_string(),
+        })?;
+    }
+
+    if group.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id,
+        })?;
+    }
+
+    let mut index_hashes = vec![];
+    for index in group.directory_indexes() {
+        index_hashes.push( AnyLinkableHash::from( index.path_entry_hash()? ) );
+    }
+
+    if !index_hashes.contains( base ) {
+        Err(guest_error!(format!("Group directory link base is not an index derived from the tagged group revision ({})", group_rev )))?;
+    }
+
+    Ok(())
+}
+
+fn validate_handle_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let handle_tag = GroupHandleTag::try_from( tag )?;
+
+    if let Err(message) = check_group_handle( &handle_tag.handle ) {
+        Err(guest_error!(message))?;
+    }
+
+    if *base != group_handle_path( &handle_tag.handle ).path_entry_hash()?.into() {
+        Err(guest_error!(format!("Group handle link base must be the anchor for handle '{}'", handle_tag.handle )))?;
+    }
+
+    let group_id = trace_origin_root( &handle_tag.group_rev )?.0;
+
+    if *target != group_id.clone().into() {
+        Err(guest_error!(format!("Group handle link target must be the ID of the tagged group revision ({})", group_id )))?;
+    }
+
+    let group : GroupEntry = must_get_valid_record( handle_tag.group_rev.to_owned() )?.try_into()?;
+
+    if !group.is_admin( &create.author ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: create.author.to_owned(),
+            operation: "manage its handle".to_string(),
+        })?;
+    }
+
+    if group.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id,
+        })?;
+    }
+
+    if let Some(previous) = handle_tag.previous {
+        let prev_create = match must_get_action( previous.link.to_owned() )?.action().to_owned() {
+            Action::CreateLink(action) => action,
+            _ => Err(guest_error!(format!("Group handle transfer must reference a create link action ({})", previous.link )))?,
+        };
+
+        if LinkTypes::from_type( prev_create.zome_index, prev_create.link_type )? != Some(LinkTypes::GroupHandle)
+            || prev_create.base_address != *base {
+            Err(guest_error!(format!("Group handle transfer must reference a handle link for '{}'", handle_tag.handle )))?;
+        }
+
+        let prev_group_id = trace_origin_root( &previous.group_rev )?.0;
+
+        if prev_create.target_address != prev_group_id.clone().into() {
+            Err(guest_error!(format!("Group handle transfer authority must be a revision of the previous handle's group ({})", previous.group_rev )))?;
+        }
+
+        // The authority cannot predate the revision that the previous handle link points at
+        let prev_tag = GroupHandleTag::try_from( prev_create.tag.to_owned() )?;
+
+        if !is_same_or_later_revision( &previous.group_rev, &prev_tag.group_rev )? {
+            Err(guest_error!(format!(
+                "Group handle transfer authority must be the previous handle's group revision ({}) or a later one",
+                prev_tag.group_rev,
+            )))?;
+        }
+
+        let prev_group : GroupEntry = must_get_valid_record( previous.group_rev.to_owned() )?.try_into()?;
+
+        if !prev_group.is_admin( &create.author ) {
+            Err(CoopContentError::NotAnAdmin {
+                group_id: prev_group_id,
+                agent: create.author.to_owned(),
+                operation: "transfer its handle".to_string(),
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_join_request_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let group_id = match base.clone().into_action_hash() {
+        Some(hash) => hash,
+        None => Err(guest_error!(format!("Join request link base must be a group ID; not '{}'", base )))?,
+    };
+
+    if group_id != trace_origin_root( &group_id )?.0 {
+        Err(CoopContentError::NotAnId {
+            address: group_id.to_owned(),
+        })?;
+    }
+
+    let group : GroupEntry = must_get_valid_record( group_id.to_owned() )?.try_into()?;
+
+    if group.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id,
+        })?;
+    }
+
+    if *target != create.author.clone().into() {
+        Err(guest_error!(format!("Join request link target must be the requesting agent ({})", create.author )))?;
+    }
+
+    let message = tag.into_inner();
+
+    if message.len() > JOIN_REQUEST_MESSAGE_MAX_BYTES {
+        Err(guest_error!(format!("Join request message cannot be larger than {} bytes", JOIN_REQUEST_MESSAGE_MAX_BYTES )))?;
+    }
+
+    if let Err(err) = String::from_utf8( message ) {
+        Err(guest_error!(format!("Join request message must be a UTF8 string: {}", err )))?;
+    }
+
+    Ok(())
+}
+
+fn validate_merge_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let (merged_rev, merge_rev) = match (base.clone().into_action_hash(), target.clone().into_action_hash()) {
+        (Some(base_addr), Some(target_addr)) => (base_addr, target_addr),
+        _ => Err(guest_error!("Group merge link base and target must be group revisions".to_string()))?,
+    };
+
+    // Verify that both are group entries
+    let merged_group : GroupEntry = summon_app_entry( base )?;
+    let merge_group : GroupEntry = summon_app_entry( target )?;
+
+    let group_id = trace_origin_root( &merge_rev )?.0;
+
+    if trace_origin_root( &merged_rev )?.0 != group_id {
+        Err(guest_error!("Group merge link base and target must be revisions of the same group".to_string()))?;
+    }
+
+    if !merge_group.is_admin( &create.author ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id,
+            agent: create.author.to_owned(),
+            operation: "merge its forks".to_string(),
+        })?;
+    }
+
+    // An update has a single parent, so a merge revision can only descend from the fork point of
+    // the merged head; it must be later than the merged head and include its contributor changes
+    let merged_history : Vec<ActionHash> = trace_origin( &merged_rev )?.into_iter()
+        .map( |(addr, _)| addr )
+        .collect();
+    let fork_point = trace_origin( &merge_rev )?.into_iter()
+        .map( |(addr, _)| addr )
+        .find( |addr| merged_history.contains( addr ) )
+        .ok_or( guest_error!("Group merge link base and target do not share a group revision".to_string()) )?;
+
+    if fork_point == merged_rev {
+        return Ok(());
+    }
+
+    if must_get_action( merge_rev.to_owned() )?.action().timestamp()
+        <= must_get_action( merged_rev.to_owned() )?.action().timestamp() {
+        Err(guest_error!(format!("Group merge link target must be later than the merged revision ({})", merged_rev )))?;
+    }
+
+    let ancestor : GroupEntry = must_get_valid_record( fork_point.to_owned() )?.try_into()?;
+
+    for agent in merged_group.contributors() {
+        if !ancestor.is_contributor( &agent ) && !merge_group.is_contributor( &agent ) {
+            Err(guest_error!(format!("Group merge revision ({}) is missing contributor '{}' added by the merged revision", merge_rev, agent )))?;
+        }
+    }
+
+    let removed_admins = ancestor.admins.iter()
+        .filter( |agent| !merged_group.admins.contains( agent ) && merge_group.admins.contains( agent ) );
+    let removed_members = ancestor.members.iter()
+        .filter( |agent| !merged_group.members.contains( agent ) && merge_group.members.contains( agent ) );
+
+    if let Some(agent) = removed_admins.chain( removed_members ).next() {
+        Err(guest_error!(format!("Group merge revision ({}) keeps '{}' who was removed by the merged revision", merge_rev, agent )))?;
     }
 
     Ok(())
 }
 
+fn validate_memo_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let group_rev = match base.clone().into_action_hash() {
+        Some(hash) => hash,
+        None => Err(guest_error!(format!("Group memo link base must be a group revision; not '{}'", base )))?,
+    };
+
+    // Verify that the base is a group entry
+    let _ : GroupEntry = summon_app_entry( base )?;
+    let group_id = trace_origin_root( &group_rev )?.0;
+
+    if *target != group_id.clone().into() {
+        Err(guest_error!(format!("Group memo link target must be the ID of the base group revision ({})", group_id )))?;
+    }
+
+    if must_get_action( group_rev.to_owned() )?.action().author() != &create.author {
+        Err(guest_error!(format!("Group memo links can only be made by the author of the group revision ({})", group_rev )))?;
+    }
+
+    let memo = tag.into_inner();
+
+    if memo.len() > GROUP_MEMO_MAX_BYTES {
+        Err(guest_error!(format!("Group memo cannot be larger than {} bytes", GROUP_MEMO_MAX_BYTES )))?;
+    }
+
+    if let Err(err) = String::from_utf8( memo ) {
+        Err(guest_error!(format!("Group memo must be a UTF8 string: {}", err )))?;
+    }
+
+    Ok(())
+}
+
+fn validate_content_lock_link(
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let lock_tag = match ContentLockTag::try_from( tag ) {
+        Ok(lock_tag) => lock_tag,
+        Err(err) => Err(CoopContentError::MalformedTag {
+            link_type: "ContentLock".to_string(),
+            reason: format!("must be a serialized ContentLockTag: {:?}", err ),
+        })?,
+    };
+    let group_id = trace_origin_root( &lock_tag.group_rev )?.0;
+
+    if *target != group_id.clone().into() {
+        Err(guest_error!(format!("Content lock link target must be the ID of the tagged group revision ({})", group_id )))?;
+    }
+
+    let group : GroupEntry = must_get_valid_record( lock_tag.group_rev.to_owned() )?.try_into()?;
+
+    if !group.is_contributor( &create.author ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: group_id.to_owned(),
+            agent: create.author.to_owned(),
+        })?;
+    }
+
+    if group.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id,
+        })?;
+    }
+
+    let max_expires_at = ( create.timestamp + std::time::Duration::from_secs( CONTENT_LOCK_MAX_DURATION ) )
+        .map_err( |err| guest_error!(format!("Invalid content lock expiry: {}", err )) )?;
+
+    if lock_tag.expires_at <= create.timestamp || lock_tag.expires_at > max_expires_at {
+        Err(guest_error!(format!(
+            "Content lock must expire after it is created and within {} seconds",
+            CONTENT_LOCK_MAX_DURATION,
+        )))?;
+    }
+
+    Ok(())
+}
+
+fn validate_proposal_link(
+    base: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let anchor : ContributionAnchors = summon_app_entry( base )?;
+
+    if anchor.is_archive() {
+        Err(guest_error!("Contribution proposal links cannot be based on an archive anchor".to_string()))?;
+    }
+
+    let proposal_tag = match ContributionProposalTag::try_from( tag ) {
+        Ok(proposal_tag) => proposal_tag,
+        Err(reason) => Err(CoopContentError::MalformedTag {
+            link_type: "ContributionProposal".to_string(),
+            reason,
+        })?,
+    };
+    let group_id = trace_origin_root( anchor.group() )?.0;
+
+    if trace_origin_root( &proposal_tag.group_rev )?.0 != group_id {
+        Err(CoopContentError::NotARevision {
+            id: group_id.to_owned(),
+            address: proposal_tag.group_rev.to_owned(),
+        })?
+    }
+
+    let group : GroupEntry = must_get_valid_record( proposal_tag.group_rev.to_owned() )?.try_into()?;
+
+    if !group.is_contributor( &create.author ) {
+        Err(CoopContentError::NotAContributor {
+            group_id,
+            agent: create.author.to_owned(),
+        })?;
+    }
+
+    if let (
+        AnyLinkableHashPrimitive::Action(id_addr),
+        AnyLinkableHashPrimitive::Action(prev_addr)
+    ) = (proposal_tag.content_id.into_primitive(), proposal_tag.content_prev.into_primitive()) {
+        if id_addr != trace_origin_root( &prev_addr )?.0 {
+            Err(CoopContentError::NotARevision {
+                id: id_addr,
+                address: prev_addr,
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_governance_proposal_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let proposal : GovernanceProposalEntry = summon_app_entry( target )?;
+    let group_id = trace_origin_root( &proposal.group_rev )?.0;
+
+    if *base != group_id.clone().into() {
+        Err(guest_error!(format!("Governance proposal link base must be the ID of the proposal's group ({})", group_id )))?;
+    }
+
+    if must_get_action( action_address( target )? )?.action().author() != &create.author {
+        Err(guest_error!("Governance proposal links can only be made by the author of the proposal".to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn validate_governance_vote_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let vote : GovernanceVoteEntry = summon_app_entry( target )?;
+
+    if *base != vote.proposal.clone().into() {
+        Err(guest_error!(format!("Governance vote link base must be the voted proposal ({})", vote.proposal )))?;
+    }
+
+    if must_get_action( action_address( target )? )?.action().author() != &create.author {
+        Err(guest_error!("Governance vote links can only be made by the author of the vote".to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn validate_governance_commit_link(
+    base: &AnyLinkableHash,
+    target: &AnyLinkableHash,
+    tag: LinkTag,
+    create: &CreateLink,
+) -> ExternResult<()> {
+    let proposal_id = action_address( base )?;
+    let proposal : GovernanceProposalEntry = summon_app_entry( base )?;
+    let commit_tag = match GovernanceCommitTag::try_from( tag ) {
+        Ok(commit_tag) => commit_tag,
+        Err(err) => Err(CoopContentError::MalformedTag {
+            link_type: "GovernanceCommit".to_string(),
+            reason: format!("must be a serialized GovernanceCommitTag: {:?}", err ),
+        })?,
+    };
+
+    // The target must be the group update that applies the proposal's changes
+    let update_rev = action_address( target )?;
+    let update = match must_get_action( update_rev.to_owned() )?.action().to_owned() {
+        Action::Update(action) => action,
+        _ => Err(guest_error!(format!("Governance commit link target must be a group update ({})", update_rev )))?,
+    };
+
+    if update.original_action_address != proposal.group_rev {
+        Err(guest_error!(format!("Governance commit must update the proposal's group revision ({})", proposal.group_rev )))?;
+    }
+
+    if update.author != create.author {
+        Err(guest_error!("Governance commit links can only be made by the author of the group update".to_string()))?;
+    }
+
+    let group : GroupEntry = must_get_valid_record( proposal.group_rev.to_owned() )?.try_into()?;
+    let next_group : GroupEntry = summon_app_entry( target )?;
+
+    if !group.is_admin( &create.author ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: trace_origin_root( &proposal.group_rev )?.0,
+            agent: create.author.to_owned(),
+            operation: "commit governance proposals".to_string(),
+        })?;
+    }
+
+    if !group.is_governance_change( &next_group, &proposal.changes ) {
+        Err(guest_error!(format!("Governance commit group update does not match the changes of proposal '{}'", proposal_id )))?;
+    }
+
+    // Tally the approving votes of unique contributors.  Proposals and votes cannot be deleted and
+    // each agent can only vote once, so a cited vote cannot have been retracted or replaced.
+    let mut voters : Vec<AgentPubKey> = vec![];
+
+    for vote_addr in commit_tag.votes.iter() {
+        let record = must_get_valid_record( vote_addr.to_owned() )?;
+        let voter = record.action().author().to_owned();
+        let vote_time = record.action().timestamp();
+        let vote : GovernanceVoteEntry = record.try_into()?;
+
+        if vote.proposal != proposal_id || !vote.approve {
+            Err(guest_error!(format!("Governance commit vote '{}' is not an approval of proposal '{}'", vote_addr, proposal_id )))?;
+        }
+
+        if vote_time > create.timestamp {
+            Err(guest_error!(format!("Governance commit vote '{}' was made after the commit", vote_addr )))?;
+        }
+
+        if voters.contains( &voter ) {
+            Err(guest_error!(format!("Governance commit counts more than one vote from agent '{}'", voter )))?;
+        }
+
+        // Vote creation already requires the voter to be a contributor of the proposal's group
+        // revision
+        voters.push( voter );
+    }
+
+    let quorum = match group.governance_quorum() {
+        Ok(quorum) => quorum,
+        Err(message) => Err(guest_error!(message))?,
+    };
+
+    if voters.len() < quorum {
+        Err(CoopContentError::QuorumNotReached {
+            proposal: proposal_id,
+            approvals: voters.len(),
+            quorum,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Get the action hash of a governance link base or target
+fn action_address(address: &AnyLinkableHash) -> ExternResult<ActionHash> {
+    match address.clone().into_action_hash() {
+        Some(hash) => Ok( hash ),
+        None => Err(guest_error!(format!("Governance link address must be an action hash; not '{}'", address ))),
+    }
+}
+
 
 pub fn validation(
     base_address: AnyLinkableHash,
@@ -67,6 +769,7 @@ pub fn validation(
     match link_type {
         LinkTypes::Contribution => {
             validate_content_link_base( &base_address, &create )?;
+            validate_contribution_type( &base_address, tag, &create )?;
 
             valid!()
         },
@@ -77,6 +780,9 @@ pub fn validation(
                 Ok(text) => text,
                 Err(err) => invalid!(format!("Contribution update link tag must be a UTF8 string: {}", err )),
             };
+            let (tag_str, group_rev) = split_contribution_tag( &tag_str );
+
+            validate_update_review( &base_address, group_rev, &create )?;
 
             if !tag_str.contains(":") {
                 invalid!(format!("Contribution update link has malformed tag: {}", tag_str ))
@@ -129,12 +835,63 @@ pub fn validation(
             valid!()
         },
         LinkTypes::GroupAuth => {
-            validate_anchor_link_base( &base_address, &target_address, &create )?;
+            validate_anchor_link_base( &base_address, &target_address, &link_type, &create )?;
 
             valid!()
         },
         LinkTypes::GroupAuthArchive => {
-            validate_anchor_link_base( &base_address, &target_address, &create )?;
+            validate_anchor_link_base( &base_address, &target_address, &link_type, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupDirectory => {
+            validate_directory_link( &base_address, &target_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupHandle => {
+            validate_handle_link( &base_address, &target_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupJoinRequest => {
+            validate_join_request_link( &base_address, &target_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupMerge => {
+            validate_merge_link( &base_address, &target_address, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupRevisionMemo => {
+            validate_memo_link( &base_address, &target_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::ContentLock => {
+            validate_content_lock_link( &target_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::ContributionProposal => {
+            validate_content_link_base( &base_address, &create )?;
+            validate_proposal_link( &base_address, tag, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GroupGovernanceProposal => {
+            validate_governance_proposal_link( &base_address, &target_address, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GovernanceVote => {
+            validate_governance_vote_link( &base_address, &target_address, &create )?;
+
+            valid!()
+        },
+        LinkTypes::GovernanceCommit => {
+            validate_governance_commit_link( &base_address, &target_address, tag, &create )?;
 
             valid!()
         },
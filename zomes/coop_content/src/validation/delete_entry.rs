@@ -29,6 +29,10 @@ pub fn validation(
         EntryTypesUnit::ArchivedContributionsAnchor => {
             invalid!("Anchors are required for the continuity of group content evolution".to_string())
         },
+        EntryTypesUnit::GovernanceProposal | EntryTypesUnit::GovernanceVote => {
+            // A commit counts votes without knowing about deletes, so proposals and votes are final
+            invalid!("Governance proposals and votes cannot be deleted".to_string())
+        },
         // entry_type_unit => invalid!(format!("Delete validation not implemented for entry type: {:?}", entry_type_unit )),
     }
 }
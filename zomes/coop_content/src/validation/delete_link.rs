@@ -3,11 +3,16 @@ use crate::{
     hdi_extensions,
     LinkTypes,
     GroupEntry,
+    GroupHandleTag,
+    ContentLockTag,
+    ContributionProposalTag,
     ContributionAnchors,
+    CoopContentError,
 };
 use hdi::prelude::*;
 use hdi_extensions::{
     summon_app_entry,
+    trace_origin_root,
     // AnyLinkableHashTransformer,
     // Macros
     valid, invalid,
@@ -46,7 +51,10 @@ pub fn validation(
                     let group : GroupEntry = must_get_valid_record( entry.group().to_owned() )?.try_into()?;
 
                     if !group.contributors().contains( &delete.author )  {
-                        invalid!(format!("A content [update] link based on an archived contributions anchor can only be deleted by an admin in the anchor's group revision ({})", entry.group() ))
+                        invalid!(CoopContentError::NotAContributor {
+                            group_id: trace_origin_root( entry.group() )?.0,
+                            agent: delete.author.to_owned(),
+                        }.into())
                     }
                 },
             };
@@ -64,10 +72,107 @@ pub fn validation(
 
             valid!()
         },
-        LinkTypes::GroupAuth | LinkTypes::GroupAuthArchive => {
+        LinkTypes::GroupAuth | LinkTypes::GroupAuthArchive | LinkTypes::GroupMerge | LinkTypes::GroupRevisionMemo => {
             // Never allowed because the way to remove members is by updating the group.  Once a
-            // GroupAuth link is successfully made, it must be valid forever.
+            // GroupAuth (or merge/memo) link is successfully made, it must be valid forever.
             invalid!(format!("Once created, group auth links cannot be deleted"))
         },
+        LinkTypes::GroupDirectory => {
+            // Deletion is valid when the author created the link or is an admin in the tagged group
+            // revision
+            if create_link.author != delete.author {
+                let group_rev = match ActionHash::from_raw_39( create_link.tag.to_owned().into_inner() ) {
+                    Ok(addr) => addr,
+                    Err(err) => invalid!(CoopContentError::MalformedTag {
+                        link_type: "GroupDirectory".to_string(),
+                        reason: format!("must be a group revision: {}", err ),
+                    }.into()),
+                };
+                let group : GroupEntry = must_get_valid_record( group_rev.to_owned() )?.try_into()?;
+
+                if !group.is_admin( &delete.author ) {
+                    invalid!(CoopContentError::NotAnAdmin {
+                        group_id: trace_origin_root( &group_rev )?.0,
+                        agent: delete.author.to_owned(),
+                        operation: "remove another agent's directory listing".to_string(),
+                    }.into())
+                }
+            }
+
+            valid!()
+        },
+        LinkTypes::GroupHandle => {
+            // Releasing a handle is valid when the author created the link or is an admin in the
+            // tagged group revision (the CSR ignores releases by agents who were no longer admins
+            // of the group's latest revision)
+            if create_link.author != delete.author {
+                let handle_tag = GroupHandleTag::try_from( create_link.tag.to_owned() )?;
+                let group : GroupEntry = must_get_valid_record( handle_tag.group_rev.to_owned() )?.try_into()?;
+
+                if !group.is_admin( &delete.author ) {
+                    invalid!(CoopContentError::NotAnAdmin {
+                        group_id: trace_origin_root( &handle_tag.group_rev )?.0,
+                        agent: delete.author.to_owned(),
+                        operation: "manage its handle".to_string(),
+                    }.into())
+                }
+            }
+
+            valid!()
+        },
+        LinkTypes::GroupJoinRequest => {
+            // Only the requesting agent can cancel a join request; admins approve requests by
+            // updating the group instead
+            if create_link.author != delete.author {
+                invalid!(format!("A join request can only be deleted by the requesting agent ({})", create_link.author ))
+            }
+
+            valid!()
+        },
+        LinkTypes::ContentLock => {
+            // Releasing a lock is valid when the author created the link; breaking it is valid
+            // when the author is an admin in the tagged group revision
+            if create_link.author != delete.author {
+                let lock_tag = ContentLockTag::try_from( create_link.tag.to_owned() )?;
+                let group : GroupEntry = must_get_valid_record( lock_tag.group_rev.to_owned() )?.try_into()?;
+
+                if !group.is_admin( &delete.author ) {
+                    invalid!(CoopContentError::NotAnAdmin {
+                        group_id: trace_origin_root( &lock_tag.group_rev )?.0,
+                        agent: delete.author.to_owned(),
+                        operation: "break content locks".to_string(),
+                    }.into())
+                }
+            }
+
+            valid!()
+        },
+        LinkTypes::ContributionProposal => {
+            // Withdrawing a proposal is valid when the author created the link; rejecting it is
+            // valid when the author is a reviewer in the tagged group revision
+            if create_link.author != delete.author {
+                let proposal_tag = match ContributionProposalTag::try_from( create_link.tag.to_owned() ) {
+                    Ok(proposal_tag) => proposal_tag,
+                    Err(reason) => invalid!(CoopContentError::MalformedTag {
+                        link_type: "ContributionProposal".to_string(),
+                        reason,
+                    }.into()),
+                };
+                let group : GroupEntry = must_get_valid_record( proposal_tag.group_rev.to_owned() )?.try_into()?;
+
+                if !group.is_reviewer( &delete.author ) {
+                    invalid!(CoopContentError::NotAReviewer {
+                        group_id: trace_origin_root( &proposal_tag.group_rev )?.0,
+                        agent: delete.author.to_owned(),
+                    }.into())
+                }
+            }
+
+            valid!()
+        },
+        LinkTypes::GroupGovernanceProposal | LinkTypes::GovernanceVote | LinkTypes::GovernanceCommit => {
+            // Proposals, votes, and commits are part of the group's governance history
+            invalid!(format!("Once created, governance links cannot be deleted"))
+        },
     }
 }
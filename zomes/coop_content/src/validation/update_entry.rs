@@ -3,9 +3,11 @@ use crate::{
     hdi_extensions,
     EntryTypes,
     GroupEntry,
+    CoopContentError,
 };
 use hdi::prelude::*;
 use hdi_extensions::{
+    trace_origin_root,
     // Macros
     valid, invalid,
 };
@@ -22,13 +24,29 @@ pub fn validation(
             let prev_group : GroupEntry = must_get_entry( original_entry_hash )?.content.try_into()?;
 
             if !prev_group.is_admin( &update.author ) {
-                invalid!("Updating a group can only be done by an admin".to_string())
+                if !prev_group.is_self_removal( &group, &update.author ) {
+                    invalid!(CoopContentError::NotAnAdmin {
+                        group_id: trace_origin_root( &update.original_action_address )?.0,
+                        agent: update.author.to_owned(),
+                        operation: "update it; members can only remove themselves".to_string(),
+                    }.into())
+                }
+
+                valid!()
             }
 
-            if group.admins.len() == 0 {
+            if group.admins.is_empty() {
                 invalid!("Admin list cannot be empty".to_string())
             }
 
+            if let Err(message) = group.check_content_types() {
+                invalid!(message)
+            }
+
+            if let Err(message) = group.governance_quorum() {
+                invalid!(message)
+            }
+
             valid!()
         },
         _ => invalid!(format!("Update validation not implemented for entry type: {:#?}", update.entry_type )),
@@ -0,0 +1,214 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    create_if_not_exists,
+    scoped_types::dedupe_links,
+};
+
+use std::collections::HashSet;
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+};
+use hdi_extensions::{
+    trace_origin_root,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContributionsAnchorEntry,
+    ArchivedContributionsAnchorEntry,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    ContinueArchiveInput,
+    ArchiveProgress,
+};
+
+
+/// The default number of links copied to an archive anchor per call
+pub const ARCHIVE_CHUNK_SIZE : usize = 100;
+
+
+/// The source links that still need to be copied to a removed member's archive anchor
+struct ArchiveJob {
+    progress: ArchiveProgress,
+    pending: Vec<(LinkTypes, Link)>,
+}
+
+fn contribution_links(
+    anchor_hash: &EntryHash,
+    link_type: LinkTypes,
+    cutoff: &Option<Timestamp>,
+) -> ExternResult<Vec<Link>> {
+    let links = dedupe_links( get_links(
+        create_link_input(
+            anchor_hash,
+            &link_type,
+            &None::<()>,
+        )?
+    )? );
+
+    Ok(
+        match cutoff {
+            Some(cutoff) => links.into_iter()
+                .filter(|link| link.timestamp <= *cutoff )
+                .collect(),
+            None => links,
+        }
+    )
+}
+
+/// Get the links of a removed member that were created before the removal (`cutoff`) and are not
+/// yet copied to their archive anchor
+fn archive_job(
+    group_id: &ActionHash,
+    group_rev: &ActionHash,
+    agent: &AgentPubKey,
+    cutoff: &Timestamp,
+) -> ExternResult<ArchiveJob> {
+    let anchor_hash = hash_entry( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?;
+    let archive_anchor_hash = hash_entry( &ArchivedContributionsAnchorEntry::new( group_rev.to_owned(), agent.to_owned() ) )?;
+
+    let mut total = 0;
+    let mut pending = vec![];
+
+    for link_type in [ LinkTypes::Contribution, LinkTypes::ContributionUpdate ] {
+        let copied : HashSet<(AnyLinkableHash, LinkTag)> = contribution_links( &archive_anchor_hash, link_type, &None )?
+            .into_iter()
+            .map( |copy| (copy.target, copy.tag) )
+            .collect();
+
+        for link in contribution_links( &anchor_hash, link_type, &Some(cutoff.to_owned()) )? {
+            total += 1;
+
+            if !copied.contains( &(link.target.to_owned(), link.tag.to_owned()) ) {
+                pending.push( (link_type, link) );
+            }
+        }
+    }
+
+    Ok(ArchiveJob {
+        progress: ArchiveProgress {
+            agent: agent.to_owned(),
+            anchor: archive_anchor_hash,
+            copied: total - pending.len(),
+            total,
+        },
+        pending,
+    })
+}
+
+/// Copy up to `limit` pending links and return the updated progress
+fn run_archive_job(job: ArchiveJob, limit: usize) -> ExternResult<ArchiveProgress> {
+    let mut progress = job.progress;
+
+    for (link_type, link) in job.pending.into_iter().take( limit ) {
+        create_link( progress.anchor.to_owned(), link.target, link_type, link.tag )?;
+        progress.copied += 1;
+    }
+    debug!("Archive progress for {}: {}/{}", progress.agent, progress.copied, progress.total );
+
+    Ok( progress )
+}
+
+/// Get the group ID, removal time, and removed members for a group revision
+fn removed_members(group_rev: &ActionHash) -> ExternResult<(ActionHash, Timestamp, Vec<AgentPubKey>)> {
+    let record = must_get( group_rev )?;
+    let prev_rev = match record.action() {
+        Action::Update(update) => update.original_action_address.to_owned(),
+        _ => return Ok(( group_rev.to_owned(), record.action().timestamp(), vec![] )),
+    };
+    let timestamp = record.action().timestamp();
+    let group : GroupEntry = record.try_into()?;
+    let prev_group : GroupEntry = must_get( &prev_rev )?.try_into()?;
+
+    Ok((
+        trace_origin_root( group_rev )?.0,
+        timestamp,
+        prev_group.contributors_diff( &group ).removed,
+    ))
+}
+
+
+/// Count the contribution (create and update) links that archiving a member would copy
+pub fn archive_link_count(
+    group_id: &ActionHash,
+    agent: &AgentPubKey,
+) -> ExternResult<usize> {
+    let anchor_hash = hash_entry( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?;
+    let mut count = 0;
+
+    for link_type in [ LinkTypes::Contribution, LinkTypes::ContributionUpdate ] {
+        count += contribution_links( &anchor_hash, link_type, &None )?.len();
+    }
+
+    Ok( count )
+}
+
+
+/// Create the archive anchor for a removed member and copy the first chunk of their links
+///
+/// Used by group updates; any remaining links are copied with [`continue_archive`].
+pub fn start_archive(
+    group_id: &ActionHash,
+    group_rev: &ActionHash,
+    agent: &AgentPubKey,
+) -> ExternResult<ArchiveProgress> {
+    let cutoff = must_get( group_rev )?.action().timestamp();
+    let archive_anchor = ArchivedContributionsAnchorEntry::new( group_rev.to_owned(), agent.to_owned() );
+    let archive_anchor_hash = hash_entry( &archive_anchor )?;
+
+    create_if_not_exists( &archive_anchor )?;
+    create_link( group_rev.to_owned(), archive_anchor_hash, LinkTypes::GroupAuthArchive, () )?;
+
+    run_archive_job( archive_job( group_id, group_rev, agent, &cutoff )?, ARCHIVE_CHUNK_SIZE )
+}
+
+
+/// Copy the next chunk of removed members' links to their archive anchors
+#[hdk_extern]
+pub fn continue_archive(input: ContinueArchiveInput) -> ExternResult<Vec<ArchiveProgress>> {
+    let (group_id, cutoff, removed) = removed_members( &input.group_rev )?;
+    let mut remaining = input.limit.unwrap_or( ARCHIVE_CHUNK_SIZE );
+    let mut results = vec![];
+
+    if let Some(agent) = input.agent.as_ref() {
+        if !removed.contains( agent ) {
+            Err(CoopContentError::NotRemoved {
+                group_rev: input.group_rev.to_owned(),
+                agent: agent.to_owned(),
+            })?
+        }
+    }
+
+    for agent in removed {
+        if input.agent.as_ref().is_some_and(|target| *target != agent ) {
+            continue;
+        }
+
+        let job = archive_job( &group_id, &input.group_rev, &agent, &cutoff )?;
+        let copying = job.pending.len().min( remaining );
+        remaining -= copying;
+
+        results.push( run_archive_job( job, copying )? );
+    }
+
+    Ok( results )
+}
+
+
+/// Get the archive copy progress for each member removed by a group revision
+#[hdk_extern]
+pub fn get_archive_status(group_rev: ActionHash) -> ExternResult<Vec<ArchiveProgress>> {
+    let (group_id, cutoff, removed) = removed_members( &group_rev )?;
+    let mut results = vec![];
+
+    for agent in removed {
+        results.push( archive_job( &group_id, &group_rev, &agent, &cutoff )?.progress );
+    }
+
+    Ok( results )
+}
@@ -0,0 +1,243 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    check_group_id,
+    create_if_not_exists,
+    get_group,
+    GroupLinks,
+    archive::{
+        continue_archive,
+        get_archive_status,
+    },
+    history::group_revisions,
+};
+
+use std::collections::HashMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContributionsAnchorEntry,
+    ArchivedContributionsAnchorEntry,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    ContinueArchiveInput,
+    GroupAudit,
+    GroupRevisionAudit,
+};
+
+
+/// The expected links of a group revision and what is actually linked
+struct RevisionState {
+    group_rev: ActionHash,
+    group: GroupEntry,
+    auth_anchors: Vec<EntryHash>,
+    archive_anchors: Vec<EntryHash>,
+    /// Removed members and their expected archive anchors
+    removed: Vec<(AgentPubKey, EntryHash)>,
+    /// Archive anchors that should be carried over from previous revisions
+    carried: Vec<EntryHash>,
+}
+
+/// Get the revisions that were merged into each merge revision
+fn merge_parents(revisions: &[(ActionHash, Option<ActionHash>)]) -> ExternResult<HashMap<ActionHash, Vec<ActionHash>>> {
+    let mut parents : HashMap<ActionHash, Vec<ActionHash>> = HashMap::new();
+
+    for (group_rev, _) in revisions {
+        let links = get_links(
+            create_link_input(
+                group_rev,
+                &LinkTypes::GroupMerge,
+                &None::<()>,
+            )?
+        )?;
+
+        for link in links {
+            if let Some(merge_rev) = link.target.into_action_hash() {
+                parents.entry( merge_rev ).or_default().push( group_rev.to_owned() );
+            }
+        }
+    }
+
+    Ok( parents )
+}
+
+fn revision_states(group_id: &ActionHash) -> ExternResult<Vec<RevisionState>> {
+    let mut revisions = vec![];
+
+    for (group_rev, previous) in group_revisions( group_id )? {
+        let record = must_get( &group_rev )?;
+        revisions.push( (record.action().timestamp(), group_rev, previous) );
+    }
+
+    // Parents and merged heads are always earlier than the revisions that follow them
+    revisions.sort_by_key( |(timestamp, ..)| *timestamp );
+
+    let revisions : Vec<(ActionHash, Option<ActionHash>)> = revisions.into_iter()
+        .map( |(_, group_rev, previous)| (group_rev, previous) )
+        .collect();
+    let merge_parents = merge_parents( &revisions )?;
+    let mut states : Vec<RevisionState> = vec![];
+
+    for (group_rev, previous) in revisions {
+        let group : GroupEntry = must_get( &group_rev )?.try_into()?;
+        let mut removed = vec![];
+        let mut carried : Vec<EntryHash> = vec![];
+
+        if let Some(prev) = previous.as_ref().and_then( |prev_rev| states.iter().find( |state| state.group_rev == *prev_rev ) ) {
+            for agent in prev.group.contributors_diff( &group ).removed {
+                let anchor_hash = hash_entry( &ArchivedContributionsAnchorEntry::new( group_rev.to_owned(), agent.to_owned() ) )?;
+                removed.push( (agent, anchor_hash) );
+            }
+        }
+
+        // A merge revision also carries the archive anchors of the heads merged into it
+        let parents = previous.iter()
+            .chain( merge_parents.get( &group_rev ).into_iter().flatten() );
+
+        for parent in states.iter().filter( |state| parents.clone().any( |addr| *addr == state.group_rev ) ) {
+            for anchor_hash in parent.removed.iter().map( |(_, anchor_hash)| anchor_hash ).chain( parent.carried.iter() ) {
+                if !carried.contains( anchor_hash ) {
+                    carried.push( anchor_hash.to_owned() );
+                }
+            }
+        }
+
+        states.push( RevisionState {
+            auth_anchors: GroupEntry::group_auth_anchor_hashes( &group_rev )?,
+            archive_anchors: GroupEntry::group_auth_archive_anchor_hashes( &group_rev )?,
+            group_rev,
+            group,
+            removed,
+            carried,
+        });
+    }
+
+    Ok( states )
+}
+
+fn audit_revision(group_id: &ActionHash, state: &RevisionState) -> ExternResult<GroupRevisionAudit> {
+    let mut expected_auth = vec![];
+    let mut missing_auth_links = vec![];
+
+    for agent in state.group.contributors() {
+        let anchor_hash = hash_entry( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?;
+
+        if !state.auth_anchors.contains( &anchor_hash ) {
+            missing_auth_links.push( agent );
+        }
+        expected_auth.push( anchor_hash );
+    }
+
+    let expected_archive : Vec<EntryHash> = state.removed.iter()
+        .map( |(_, anchor_hash)| anchor_hash.to_owned() )
+        .chain( state.carried.iter().cloned() )
+        .collect();
+
+    let missing_archive_links = expected_archive.iter()
+        .filter( |anchor_hash| !state.archive_anchors.contains( anchor_hash ) )
+        .cloned()
+        .collect();
+
+    let orphaned_anchors = state.auth_anchors.iter()
+        .filter( |anchor_hash| !expected_auth.contains( anchor_hash ) )
+        .chain(
+            state.archive_anchors.iter()
+                .filter( |anchor_hash| !expected_archive.contains( anchor_hash ) )
+        )
+        .cloned()
+        .collect();
+
+    let incomplete_archives = get_archive_status( state.group_rev.to_owned() )?.into_iter()
+        .filter( |progress| !progress.is_complete() )
+        .collect();
+
+    Ok(GroupRevisionAudit {
+        group_rev: state.group_rev.to_owned(),
+        missing_auth_links,
+        missing_archive_links,
+        incomplete_archives,
+        orphaned_anchors,
+    })
+}
+
+
+/// Check every revision of a group (including forks) for missing auth/archive links and orphaned anchors
+#[hdk_extern]
+pub fn audit_group(group_id: ActionHash) -> ExternResult<GroupAudit> {
+    check_group_id( &group_id )?;
+
+    let mut revisions = vec![];
+
+    for state in revision_states( &group_id )? {
+        revisions.push( audit_revision( &group_id, &state )? );
+    }
+
+    Ok(GroupAudit {
+        group_id,
+        revisions,
+    })
+}
+
+
+/// Create the missing auth and archive links found by [`audit_group`]
+///
+/// Only revisions where the current agent is an admin are repaired because link validation
+/// requires admin authority over the base revision.  Orphaned anchors are reported by the audit
+/// but cannot be repaired since group auth links cannot be deleted.  Returns the new link
+/// addresses.
+#[hdk_extern]
+pub fn repair_group(group_id: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    check_group_id( &group_id )?;
+
+    let agent_id = agent_id()?;
+
+    if !get_group( group_id.to_owned() )?.content.is_admin( &agent_id ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: agent_id.to_owned(),
+            operation: "repair it".to_string(),
+        })?
+    }
+
+    let mut created = vec![];
+
+    for state in revision_states( &group_id )? {
+        if !state.group.is_admin( &agent_id ) {
+            debug!("Skipping repair of revision {} because agent is not an admin", state.group_rev );
+            continue;
+        }
+
+        let audit = audit_revision( &group_id, &state )?;
+
+        for agent in audit.missing_auth_links {
+            let anchor = ContributionsAnchorEntry( group_id.to_owned(), agent );
+            create_if_not_exists( &anchor )?;
+            created.push( create_link( state.group_rev.to_owned(), hash_entry( &anchor )?, LinkTypes::GroupAuth, () )? );
+        }
+
+        for anchor_hash in audit.missing_archive_links {
+            if let Some((agent, _)) = state.removed.iter().find( |(_, removed_hash)| *removed_hash == anchor_hash ) {
+                create_if_not_exists( &ArchivedContributionsAnchorEntry::new( state.group_rev.to_owned(), agent.to_owned() ) )?;
+            }
+            created.push( create_link( state.group_rev.to_owned(), anchor_hash, LinkTypes::GroupAuthArchive, () )? );
+        }
+
+        if !audit.incomplete_archives.is_empty() {
+            continue_archive( ContinueArchiveInput {
+                group_rev: state.group_rev.to_owned(),
+                agent: None,
+                limit: None,
+            })?;
+        }
+    }
+    debug!("Repaired group '{}' with {} new links", group_id, created.len() );
+
+    Ok( created )
+}
@@ -0,0 +1,58 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    latest_group_rev,
+    group_content_types,
+};
+
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+};
+use coop_content::{
+    GroupEntry,
+};
+use coop_content_sdk::{
+    GroupContentType,
+};
+
+
+/// Get a group's declared content types and any other types found in its contributions, each
+/// with the number of content IDs using it
+///
+/// Declared types are listed first in their declared order.
+#[hdk_extern]
+pub fn get_group_content_types(group_id: ActionHash) -> ExternResult<Vec<GroupContentType>> {
+    let latest_addr = latest_group_rev( &group_id )?;
+    let group : GroupEntry = must_get( &latest_addr )?.try_into()?;
+    let declarations = group.content_types.unwrap_or_default();
+
+    let mut counts : BTreeMap<String, usize> = BTreeMap::new();
+
+    for content_type in group_content_types( &group_id )?.into_values() {
+        *counts.entry( content_type ).or_default() += 1;
+    }
+
+    let mut content_types = vec![];
+
+    for declaration in declarations {
+        content_types.push( GroupContentType {
+            count: counts.remove( &declaration.name ).unwrap_or( 0 ),
+            name: declaration.name,
+            schema: declaration.schema,
+            declared: true,
+        });
+    }
+
+    for (name, count) in counts {
+        content_types.push( GroupContentType {
+            name,
+            schema: None,
+            declared: false,
+            count,
+        });
+    }
+
+    Ok( content_types )
+}
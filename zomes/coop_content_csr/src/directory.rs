@@ -0,0 +1,193 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    check_group_id,
+    Entity,
+    get_group,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+    follow_evolutions,
+};
+use hdi_extensions::{
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    DirectoryIndex,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    GetDirectoryGroupsInput,
+};
+
+
+fn directory_links(group_id: &ActionHash, index: &DirectoryIndex) -> ExternResult<Vec<Link>> {
+    Ok(
+        get_links(
+            create_link_input(
+                &index.path_entry_hash()?,
+                &LinkTypes::GroupDirectory,
+                &None::<()>,
+            )?
+        )?.into_iter()
+            .filter( |link| link.target == group_id.clone().into() )
+            .collect()
+    )
+}
+
+/// Every directory index that any revision of the group could have been listed under
+fn group_directory_indexes(group_id: &ActionHash) -> ExternResult<Vec<DirectoryIndex>> {
+    let mut indexes = vec![];
+
+    for addr in follow_evolutions( group_id )? {
+        let group : GroupEntry = must_get( &addr )?.try_into()?;
+        indexes.extend( group.directory_indexes() );
+    }
+
+    indexes.sort();
+    indexes.dedup();
+
+    Ok( indexes )
+}
+
+
+/// List (or refresh the listing of) a group in the directory
+///
+/// The indexes are derived from the latest group revision's `metadata` (`name` and `tags`).
+/// Listings for indexes that no longer apply are removed.  Returns the indexes the group is listed
+/// under.
+#[hdk_extern]
+pub fn list_group_in_directory(group_id: ActionHash) -> ExternResult<Vec<DirectoryIndex>> {
+    check_group_id( &group_id )?;
+
+    let group = get_group( group_id.to_owned() )?;
+    let agent_id = agent_id()?;
+
+    if !group.content.is_admin( &agent_id ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: agent_id.to_owned(),
+            operation: "list it in the directory".to_string(),
+        })?
+    }
+
+    if group.content.deleted == Some(true) {
+        Err(CoopContentError::GroupDeleted {
+            group_id: group_id.to_owned(),
+        })?
+    }
+
+    let indexes = group.content.directory_indexes();
+    let rev_tag = LinkTag::new( group.action.get_raw_39().to_vec() );
+    debug!("Listing group '{}' in directory indexes: {:?}", group_id, indexes );
+
+    for index in group_directory_indexes( &group_id )? {
+        let links = directory_links( &group_id, &index )?;
+        let wanted = indexes.contains( &index );
+        let mut current = false;
+
+        for link in links {
+            if wanted && link.tag == rev_tag && !current {
+                current = true;
+                continue;
+            }
+
+            debug!("Removing stale directory link: {}", link.create_link_hash );
+            delete_link( link.create_link_hash )?;
+        }
+
+        if wanted && !current {
+            create_link(
+                index.path_entry_hash()?,
+                group_id.to_owned(),
+                LinkTypes::GroupDirectory,
+                rev_tag.to_owned(),
+            )?;
+        }
+    }
+
+    Ok( indexes )
+}
+
+
+/// Remove a group from every directory index
+#[hdk_extern]
+pub fn delist_group_from_directory(group_id: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    check_group_id( &group_id )?;
+
+    let mut deleted = vec![];
+
+    for index in group_directory_indexes( &group_id )? {
+        for link in directory_links( &group_id, &index )? {
+            deleted.push( delete_link( link.create_link_hash )? );
+        }
+    }
+    debug!("Removed {} directory links for group '{}'", deleted.len(), group_id );
+
+    Ok( deleted )
+}
+
+
+/// Search the group directory by name prefix and/or tag
+///
+/// Matches are checked against each group's latest revision so that renamed, re-tagged, or
+/// deleted groups are not returned for stale listings.
+#[hdk_extern]
+pub fn get_directory_groups(input: GetDirectoryGroupsInput) -> ExternResult<Vec<Entity<GroupEntry>>> {
+    let name_prefix = input.name.as_ref()
+        .map( |name| name.trim().to_lowercase() );
+    let tag = input.tag.as_ref()
+        .map( |tag| DirectoryIndex::tag( tag ) );
+
+    let index = match (&tag, &name_prefix) {
+        (Some(index), _) => index.to_owned(),
+        (None, Some(prefix)) => DirectoryIndex::name( prefix )
+            .ok_or(guest_error!(format!(
+                "Directory name search must be at least {} characters",
+                coop_content::DIRECTORY_NAME_PREFIX_LENGTH,
+            )))?,
+        (None, None) => DirectoryIndex::All,
+    };
+
+    let links = get_links(
+        create_link_input(
+            &index.path_entry_hash()?,
+            &LinkTypes::GroupDirectory,
+            &None::<()>,
+        )?
+    )?;
+
+    let mut group_ids = vec![];
+    for link in links {
+        if let Some(group_id) = link.target.into_action_hash() {
+            if !group_ids.contains( &group_id ) {
+                group_ids.push( group_id );
+            }
+        }
+    }
+
+    Ok(
+        group_ids.into_iter()
+            .filter_map( |group_id| get_group( group_id ).ok() )
+            .filter( |group| {
+                let indexes = group.content.directory_indexes();
+
+                group.content.deleted != Some(true)
+                    && indexes.contains( &index )
+                    && tag.as_ref().is_none_or( |tag| indexes.contains( tag ) )
+                    && name_prefix.as_ref().is_none_or( |prefix| {
+                        group.content.directory_name()
+                            .is_some_and( |name| name.to_lowercase().starts_with( prefix ) )
+                    })
+            })
+            .collect()
+    )
+}
@@ -0,0 +1,333 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    GroupLinks,
+    ContributionsLinks,
+    ArchivedContributionsLinks,
+    resolve_group_state,
+    follow_update_map,
+    group_content_targets_shortcuts,
+    group_content_targets_full_trace,
+    scoped_types::{
+        dedupe_links,
+        parse_contribution_update_tag,
+    },
+};
+
+use std::collections::HashMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+    must_get_record_details,
+    resolve_action_addr,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContributionsAnchorEntry,
+    ArchivedContributionsAnchorEntry,
+};
+use coop_content_sdk::{
+    create_link_input,
+    GetGroupContentInput,
+    GetAllGroupContentInput,
+    ContentResolution,
+    ContentDivergence,
+    ResolutionReason,
+    ResolutionStep,
+};
+
+
+/// The contribution links of a group revision's anchors in the order used by resolution
+/// (archive anchors first)
+struct AnchorLinks {
+    anchor: EntryHash,
+    archived: bool,
+    creates: Vec<Link>,
+    updates: Vec<Link>,
+}
+
+fn group_anchor_links(group_rev: &ActionHash) -> ExternResult<Vec<AnchorLinks>> {
+    let mut anchors = vec![];
+
+    for anchor_hash in GroupEntry::group_auth_archive_anchor_hashes( group_rev )? {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+        anchors.push( AnchorLinks {
+            creates: contribution_links( &anchor_hash )?,
+            updates: anchor.update_links()?,
+            anchor: anchor_hash,
+            archived: true,
+        });
+    }
+
+    for anchor_hash in GroupEntry::group_auth_anchor_hashes( group_rev )? {
+        let anchor : ContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+        anchors.push( AnchorLinks {
+            creates: contribution_links( &anchor_hash )?,
+            updates: anchor.update_links()?,
+            anchor: anchor_hash,
+            archived: false,
+        });
+    }
+
+    Ok( anchors )
+}
+
+fn contribution_links(anchor_hash: &EntryHash) -> ExternResult<Vec<Link>> {
+    Ok( dedupe_links( get_links(
+        create_link_input(
+            anchor_hash,
+            &LinkTypes::Contribution,
+            &None::<()>,
+        )?
+    )? ) )
+}
+
+/// Archive anchor links are never after the cutoff because they are copies of links created
+/// before the removal
+fn is_after(anchor: &AnchorLinks, link: &Link, cutoff: &Option<Timestamp>) -> bool {
+    !anchor.archived && cutoff.is_some_and( |cutoff| link.timestamp > cutoff )
+}
+
+fn anchor_reason(archived: bool) -> ResolutionReason {
+    match archived {
+        true => ResolutionReason::ArchivedContributor,
+        false => ResolutionReason::Contributor,
+    }
+}
+
+/// Record the contribution links for the content and return whether any were accepted
+fn explain_creates(
+    content_id: &AnyLinkableHash,
+    anchors: &[AnchorLinks],
+    cutoff: &Option<Timestamp>,
+    steps: &mut Vec<ResolutionStep>,
+) -> bool {
+    let mut found = false;
+
+    for anchor in anchors {
+        for link in anchor.creates.iter().filter( |link| link.target == *content_id ) {
+            let accepted = !is_after( anchor, link, cutoff );
+            found = found || accepted;
+
+            steps.push( ResolutionStep::ContributionLink {
+                anchor: anchor.anchor.to_owned(),
+                target: link.target.to_owned(),
+                accepted,
+                reason: match accepted {
+                    true => anchor_reason( anchor.archived ),
+                    false => ResolutionReason::AfterCutoff,
+                },
+            });
+        }
+    }
+
+    found
+}
+
+fn explain_shortcuts(
+    content_id: &AnyLinkableHash,
+    anchors: &[AnchorLinks],
+    cutoff: &Option<Timestamp>,
+) -> (Vec<AnyLinkableHash>, Vec<ResolutionStep>) {
+    let mut steps = vec![];
+    let found = explain_creates( content_id, anchors, cutoff, &mut steps );
+
+    // Same as the shortcut method; a later link from the same revision replaces an earlier one
+    let mut updates = HashMap::new();
+
+    for anchor in anchors {
+        for link in anchor.updates.iter().filter( |link| !is_after( anchor, link, cutoff ) ) {
+            if let Some((_, content_prev, content_next)) = parse_update_link( link ) {
+                updates.insert( content_prev, content_next );
+            }
+        }
+    }
+
+    let evolutions = match found {
+        true => follow_update_map( content_id, &updates ),
+        false => vec![],
+    };
+
+    for anchor in anchors {
+        for link in anchor.updates.iter() {
+            let (update_id, content_prev, content_next) = match parse_update_link( link ) {
+                Some(parts) => parts,
+                None => continue,
+            };
+
+            if update_id != *content_id {
+                continue;
+            }
+
+            let reason = if is_after( anchor, link, cutoff ) {
+                ResolutionReason::AfterCutoff
+            } else if updates.get( &content_prev ) != Some(&content_next) {
+                ResolutionReason::Superseded
+            } else if !evolutions.contains( &content_prev ) {
+                ResolutionReason::Unreachable
+            } else {
+                anchor_reason( anchor.archived )
+            };
+
+            steps.push( ResolutionStep::UpdateLink {
+                anchor: anchor.anchor.to_owned(),
+                accepted: matches!( reason, ResolutionReason::Contributor | ResolutionReason::ArchivedContributor ),
+                content_prev,
+                content_next,
+                reason,
+            });
+        }
+    }
+
+    (evolutions, steps)
+}
+
+fn explain_full_trace(
+    content_id: &AnyLinkableHash,
+    group: &GroupEntry,
+    anchors: &[AnchorLinks],
+    cutoff: &Option<Timestamp>,
+) -> ExternResult<(Vec<AnyLinkableHash>, Vec<ResolutionStep>)> {
+    let mut steps = vec![];
+    explain_creates( content_id, anchors, cutoff, &mut steps );
+
+    // Same as the full trace method; archived updates are exceptions to the group's authorities
+    let mut exceptions = vec![];
+
+    for anchor in anchors.iter().filter( |anchor| anchor.archived ) {
+        for link in anchor.updates.iter() {
+            match link.target.clone().into_action_hash() {
+                Some(addr) => exceptions.push( addr ),
+                None => {
+                    if let Some((update_id, content_prev, content_next)) = parse_update_link( link ) {
+                        if update_id == *content_id {
+                            steps.push( ResolutionStep::UpdateLink {
+                                anchor: anchor.anchor.to_owned(),
+                                content_prev,
+                                content_next,
+                                accepted: false,
+                                reason: ResolutionReason::NonActionTarget,
+                            });
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    let contributors = group.contributors();
+    let mut evolutions = vec![];
+    let mut next_addr = Some( resolve_action_addr( content_id )? );
+
+    while let Some(addr) = next_addr {
+        let mut candidates = vec![];
+        let mut rejected = vec![];
+
+        for update in must_get_record_details( &addr )?.updates {
+            let reason = if cutoff.is_some_and( |cutoff| update.action().timestamp() > cutoff ) {
+                ResolutionReason::AfterCutoff
+            } else if contributors.contains( update.action().author() ) {
+                ResolutionReason::Contributor
+            } else if exceptions.contains( update.action_address() ) {
+                ResolutionReason::ArchivedException
+            } else {
+                ResolutionReason::NonContributor
+            };
+
+            match reason {
+                ResolutionReason::Contributor | ResolutionReason::ArchivedException => candidates.push( (update, reason) ),
+                _ => rejected.push( (update, reason) ),
+            }
+        }
+
+        next_addr = candidates.iter()
+            .min_by_key( |(update, _)| update.action().timestamp() )
+            .map( |(update, _)| update.action_address().to_owned() );
+
+        for (update, reason) in candidates.into_iter().chain( rejected ) {
+            let accepted = next_addr.as_ref() == Some(update.action_address());
+
+            steps.push( ResolutionStep::Update {
+                content_prev: addr.to_owned(),
+                content_next: update.action_address().to_owned(),
+                author: update.action().author().to_owned(),
+                reason: match (accepted, &reason) {
+                    (false, ResolutionReason::Contributor | ResolutionReason::ArchivedException) => ResolutionReason::NotEarliest,
+                    _ => reason,
+                },
+                accepted,
+            });
+        }
+
+        evolutions.push( addr.into() );
+    }
+
+    Ok( (evolutions, steps) )
+}
+
+fn parse_update_link(link: &Link) -> Option<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)> {
+    let (content_id, content_prev) = parse_contribution_update_tag( &link.tag )?;
+
+    Some(( content_id, content_prev, link.target.to_owned() ))
+}
+
+
+/// Resolve a content's evolutions and report each link and update that was accepted or rejected
+///
+/// Uses the same method as [`crate::get_group_content_evolutions`] based on `full_trace`.
+#[hdk_extern]
+pub fn explain_group_content_evolutions(input: GetGroupContentInput) -> ExternResult<ContentResolution> {
+    debug!("Explain group content evolutions: {:?}", input );
+    let (record, cutoff) = resolve_group_state( &input.group_id, &input.as_of )?;
+    let group_rev = record.action_address().to_owned();
+    let group : GroupEntry = record.try_into()?;
+    let anchors = group_anchor_links( &group_rev )?;
+    let full_trace = input.full_trace.unwrap_or( false );
+
+    let (evolutions, steps) = match full_trace {
+        false => explain_shortcuts( &input.content_id, &anchors, &cutoff ),
+        true => explain_full_trace( &input.content_id, &group, &anchors, &cutoff )?,
+    };
+
+    Ok(ContentResolution {
+        content_id: input.content_id,
+        group_rev,
+        full_trace,
+        evolutions,
+        steps,
+    })
+}
+
+
+/// Resolve all group content with both the shortcut and full-trace methods and return the content
+/// where their latest revisions differ
+///
+/// The `full_trace` input field is ignored.
+#[hdk_extern]
+pub fn check_group_content_consistency(input: GetAllGroupContentInput) -> ExternResult<Vec<ContentDivergence>> {
+    debug!("Check group content consistency: {:?}", input );
+    let shortcuts : HashMap<AnyLinkableHash, AnyLinkableHash> = group_content_targets_shortcuts(
+        input.group_id.to_owned(), input.content_type.to_owned(), input.content_base.to_owned(), input.as_of.to_owned(), input.include_proposals,
+    )?.into_iter().collect();
+    let full_trace : HashMap<AnyLinkableHash, AnyLinkableHash> = group_content_targets_full_trace(
+        input.group_id, input.content_type, input.content_base, input.as_of, input.include_proposals,
+    )?.into_iter().collect();
+
+    let mut content_ids : Vec<&AnyLinkableHash> = shortcuts.keys()
+        .chain( full_trace.keys() )
+        .collect();
+    content_ids.sort_by_key( |content_id| content_id.to_string() );
+    content_ids.dedup();
+
+    Ok(
+        content_ids.into_iter()
+            .filter( |content_id| shortcuts.get( *content_id ) != full_trace.get( *content_id ) )
+            .map( |content_id| ContentDivergence {
+                content_id: content_id.to_owned(),
+                shortcuts: shortcuts.get( content_id ).cloned(),
+                full_trace: full_trace.get( content_id ).cloned(),
+            })
+            .collect()
+    )
+}
@@ -0,0 +1,197 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    latest_group_rev,
+    Entity,
+    GroupLinks,
+    get_group,
+    update_group,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+    must_get_record_details,
+};
+use hdi_extensions::{
+    trace_origin,
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    UpdateGroupInput,
+};
+
+
+/// Get every revision of a group that has not been updated
+fn group_revision_heads(group_id: &ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let mut heads = vec![];
+    let mut queue = vec![ group_id.to_owned() ];
+
+    while let Some(addr) = queue.pop() {
+        let details = must_get_record_details( &addr )?;
+
+        if details.updates.is_empty() {
+            heads.push( addr );
+        }
+
+        for update in details.updates {
+            queue.push( update.hashed.hash );
+        }
+    }
+
+    Ok( heads )
+}
+
+fn is_merged(group_rev: &ActionHash) -> ExternResult<bool> {
+    Ok(
+        !get_links(
+            create_link_input(
+                group_rev,
+                &LinkTypes::GroupMerge,
+                &None::<()>,
+            )?
+        )?.is_empty()
+    )
+}
+
+/// Get the revision heads, other than the given primary head, that have not been merged
+pub fn unmerged_forks(group_id: &ActionHash, primary: &ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let mut forks = vec![];
+
+    for head in group_revision_heads( group_id )? {
+        if head != *primary && !is_merged( &head )? {
+            forks.push( head );
+        }
+    }
+
+    Ok( forks )
+}
+
+/// Find the latest revision that is an ancestor of every head
+fn common_ancestor(primary: &ActionHash, others: &[ActionHash]) -> ExternResult<ActionHash> {
+    let mut other_histories = vec![];
+
+    for head in others {
+        let history : Vec<ActionHash> = trace_origin( head )?.into_iter()
+            .map( |(addr, _)| addr )
+            .collect();
+        other_histories.push( history );
+    }
+
+    for (addr, _) in trace_origin( primary )? {
+        if other_histories.iter().all( |history| history.contains( &addr ) ) {
+            return Ok( addr );
+        }
+    }
+
+    Err(guest_error!(format!("Revision heads do not share a common ancestor with '{}'", primary )))
+}
+
+/// Apply every branch's additions and removals (relative to the base) to the base list
+fn merge_agents(base: &[AgentPubKey], branches: &[&Vec<AgentPubKey>]) -> Vec<AgentPubKey> {
+    let mut merged = base.to_vec();
+
+    for branch in branches {
+        for agent in branch.iter() {
+            if !base.contains( agent ) && !merged.contains( agent ) {
+                merged.push( agent.to_owned() );
+            }
+        }
+    }
+
+    for branch in branches {
+        merged.retain( |agent| !base.contains( agent ) || branch.contains( agent ) );
+    }
+
+    merged
+}
+
+
+/// Get the unmerged revision heads of a group other than the one returned by `get_group`
+#[hdk_extern]
+pub fn get_group_forks(group_id: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let primary = latest_group_rev( &group_id )?;
+
+    unmerged_forks( &group_id, &primary )
+}
+
+
+/// Merge forked group revisions into a new revision
+///
+/// The merged entry applies the contributor changes of every branch (relative to their common
+/// ancestor) and keeps the other fields of the primary head.  The new revision updates the
+/// primary head, carries over the archive links of the other heads, and links each merged head
+/// to it so that it is no longer reported as a fork.
+#[hdk_extern]
+pub fn merge_group_forks(group_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
+    let group = get_group( group_id.to_owned() )?;
+
+    if group.forks.is_empty() {
+        Err(CoopContentError::NoUnmergedForks {
+            group_id: group_id.to_owned(),
+        })?
+    }
+
+    if !group.content.is_admin( &agent_id()? ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: agent_id()?,
+            operation: "merge its forks".to_string(),
+        })?
+    }
+
+    let ancestor_addr = common_ancestor( &group.action, &group.forks )?;
+    let ancestor : GroupEntry = must_get( &ancestor_addr )?.try_into()?;
+    let mut branches = vec![ group.content.to_owned() ];
+
+    for head in group.forks.iter() {
+        branches.push( must_get( head )?.try_into()? );
+    }
+    debug!("Merging {} branches of group '{}' from ancestor {}", branches.len(), group_id, ancestor_addr );
+
+    let admin_lists : Vec<&Vec<AgentPubKey>> = branches.iter().map( |branch| &branch.admins ).collect();
+    let member_lists : Vec<&Vec<AgentPubKey>> = branches.iter().map( |branch| &branch.members ).collect();
+
+    let mut entry = group.content.to_owned();
+    entry.admins = merge_agents( &ancestor.admins, &admin_lists );
+    entry.members = merge_agents( &ancestor.members, &member_lists );
+    entry.members.retain( |member| !entry.admins.contains( member ) );
+    entry.last_updated = sys_time()?.as_millis() as u64;
+
+    if entry.admins.is_empty() {
+        Err(guest_error!("Merged group would have no admins".to_string()))?
+    }
+
+    let merged = update_group( UpdateGroupInput {
+        base: group.action.to_owned(),
+        entry,
+        require_latest: true,
+        memo: None,
+    })?;
+
+    // Includes the archives carried by update_group and any started for members removed by the merge
+    let carried = GroupEntry::group_auth_archive_anchor_hashes( &merged.action )?;
+    let mut linked = vec![];
+
+    for head in group.forks.iter() {
+        for anchor_hash in GroupEntry::group_auth_archive_anchor_hashes( head )? {
+            if !carried.contains( &anchor_hash ) && !linked.contains( &anchor_hash ) {
+                create_link( merged.action.to_owned(), anchor_hash.to_owned(), LinkTypes::GroupAuthArchive, () )?;
+                linked.push( anchor_hash );
+            }
+        }
+
+        create_link( head.to_owned(), merged.action.to_owned(), LinkTypes::GroupMerge, () )?;
+    }
+
+    Ok( merged )
+}
@@ -0,0 +1,311 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    check_group_id,
+    latest_group_rev,
+    update_group,
+};
+
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use hdi_extensions::{
+    trace_origin_root,
+    ScopedTypeConnector,
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    GovernanceProposalEntry,
+    GovernanceVoteEntry,
+    GovernanceCommitTag,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    Entity,
+    UpdateGroupInput,
+    ProposeGroupChangeInput,
+    GovernanceVoteInput,
+    GovernanceProposal,
+};
+
+
+/// The vote of a single contributor
+struct Ballot {
+    vote: ActionHash,
+    approve: bool,
+}
+
+
+/// Get the vote of each contributor of the proposal's group revision
+fn proposal_ballots(
+    proposal_id: &ActionHash,
+    group: &GroupEntry,
+) -> ExternResult<BTreeMap<AgentPubKey, Ballot>> {
+    let links = get_links(
+        create_link_input(
+            proposal_id,
+            &LinkTypes::GovernanceVote,
+            &None::<()>,
+        )?
+    )?;
+    let mut ballots : BTreeMap<AgentPubKey, Ballot> = BTreeMap::new();
+
+    for link in links {
+        let vote_addr = match link.target.into_action_hash() {
+            Some(hash) => hash,
+            None => continue,
+        };
+        let record = must_get( &vote_addr )?;
+        let author = record.action().author().to_owned();
+        let vote : GovernanceVoteEntry = record.try_into()?;
+
+        if !group.is_contributor( &author ) {
+            debug!("Skipping governance vote from non-contributor: {}", author );
+            continue;
+        }
+
+        ballots.insert( author, Ballot {
+            vote: vote_addr,
+            approve: vote.approve,
+        });
+    }
+
+    Ok( ballots )
+}
+
+/// Get a governance proposal with its tally
+fn governance_proposal(proposal_id: &ActionHash) -> ExternResult<(GovernanceProposal, Vec<ActionHash>)> {
+    let record = must_get( proposal_id )?;
+    let author = record.action().author().to_owned();
+    let timestamp = record.action().timestamp();
+    let proposal : GovernanceProposalEntry = record.try_into()?;
+    let group_id = trace_origin_root( &proposal.group_rev )?.0;
+    let group : GroupEntry = must_get( &proposal.group_rev )?.try_into()?;
+    let quorum = group.governance_quorum()
+        .map_err( |message| guest_error!(message) )?;
+
+    let mut approvals = vec![];
+    let mut rejections = vec![];
+    let mut approving_votes = vec![];
+
+    for (agent, ballot) in proposal_ballots( proposal_id, &group )? {
+        if ballot.approve {
+            approvals.push( agent );
+            approving_votes.push( ballot.vote );
+        } else {
+            rejections.push( agent );
+        }
+    }
+
+    let committed = get_links(
+        create_link_input(
+            proposal_id,
+            &LinkTypes::GovernanceCommit,
+            &None::<()>,
+        )?
+    )?.into_iter()
+        .filter_map( |link| link.target.into_action_hash() )
+        .next();
+    let stale = committed.is_none()
+        && latest_group_rev( &group_id )? != proposal.group_rev;
+
+    Ok((
+        GovernanceProposal {
+            id: proposal_id.to_owned(),
+            group_id,
+            author,
+            timestamp,
+            proposal,
+            approvals,
+            rejections,
+            quorum,
+            committed,
+            stale,
+        },
+        approving_votes,
+    ))
+}
+
+/// Check that the current agent is a contributor in the given group revision
+fn check_contributor(group_rev: &ActionHash) -> ExternResult<(AgentPubKey, GroupEntry)> {
+    let agent_id = agent_id()?;
+    let group : GroupEntry = must_get( group_rev )?.try_into()?;
+
+    if !group.is_contributor( &agent_id ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: trace_origin_root( group_rev )?.0,
+            agent: agent_id.to_owned(),
+        })?
+    }
+
+    Ok( (agent_id, group) )
+}
+
+
+/// Propose changes to the latest revision of a group
+///
+/// Any contributor can propose adding or removing members or changing metadata.
+#[hdk_extern]
+pub fn propose_group_change(input: ProposeGroupChangeInput) -> ExternResult<GovernanceProposal> {
+    check_group_id( &input.group_id )?;
+
+    let group_rev = latest_group_rev( &input.group_id )?;
+    let (_, group) = check_contributor( &group_rev )?;
+
+    if let Err(reason) = input.changes.check( &group ) {
+        Err(CoopContentError::InvalidGovernanceChanges {
+            group_id: input.group_id.to_owned(),
+            reason,
+        })?
+    }
+
+    let proposal = GovernanceProposalEntry {
+        group_rev,
+        changes: input.changes,
+        description: input.description,
+    };
+
+    debug!("Creating governance proposal for group {}: {:?}", input.group_id, proposal.changes );
+    let proposal_id = create_entry( proposal.to_input() )?;
+
+    create_link( input.group_id, proposal_id.to_owned(), LinkTypes::GroupGovernanceProposal, () )?;
+
+    Ok( governance_proposal( &proposal_id )?.0 )
+}
+
+
+/// Get the governance proposals of a group, earliest first
+#[hdk_extern]
+pub fn get_group_governance_proposals(group_id: ActionHash) -> ExternResult<Vec<GovernanceProposal>> {
+    check_group_id( &group_id )?;
+
+    let links = get_links(
+        create_link_input(
+            &group_id,
+            &LinkTypes::GroupGovernanceProposal,
+            &None::<()>,
+        )?
+    )?;
+    let mut proposals = vec![];
+
+    for link in links {
+        if let Some(proposal_id) = link.target.into_action_hash() {
+            proposals.push( governance_proposal( &proposal_id )?.0 );
+        }
+    }
+
+    proposals.sort_by_key( |proposal| proposal.timestamp );
+
+    Ok( proposals )
+}
+
+
+/// Get a governance proposal with its current tally
+#[hdk_extern]
+pub fn get_governance_proposal(proposal_id: ActionHash) -> ExternResult<GovernanceProposal> {
+    Ok( governance_proposal( &proposal_id )?.0 )
+}
+
+
+/// Vote on a governance proposal
+///
+/// Only contributors of the proposal's group revision can vote.  Votes are final; each agent can
+/// vote once per proposal.
+#[hdk_extern]
+pub fn vote_on_governance_proposal(input: GovernanceVoteInput) -> ExternResult<ActionHash> {
+    let proposal : GovernanceProposalEntry = must_get( &input.proposal_id )?.try_into()?;
+    let (agent_id, _) = check_contributor( &proposal.group_rev )?;
+
+    let already_voted = get_links(
+        create_link_input(
+            &input.proposal_id,
+            &LinkTypes::GovernanceVote,
+            &None::<()>,
+        )?
+    )?.into_iter()
+        .any( |link| link.author == agent_id );
+
+    if already_voted {
+        Err(CoopContentError::AlreadyVoted {
+            proposal: input.proposal_id.to_owned(),
+            agent: agent_id.to_owned(),
+        })?
+    }
+
+    let vote = GovernanceVoteEntry {
+        proposal: input.proposal_id.to_owned(),
+        approve: input.approve,
+    };
+
+    debug!("Voting {} on governance proposal {}", if input.approve { "for" } else { "against" }, input.proposal_id );
+    let vote_id = create_entry( vote.to_input() )?;
+
+    create_link( input.proposal_id, vote_id.to_owned(), LinkTypes::GovernanceVote, () )?;
+
+    Ok( vote_id )
+}
+
+
+/// Commit an approved governance proposal as a group update
+///
+/// Only admins can commit proposals.  The proposal's group revision must still be the latest and
+/// its approvals must reach the quorum; the counted votes are linked with the commit so that
+/// validation can check the tally.
+#[hdk_extern]
+pub fn commit_governance_proposal(proposal_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
+    let (status, approving_votes) = governance_proposal( &proposal_id )?;
+    let agent_id = agent_id()?;
+    let group : GroupEntry = must_get( &status.proposal.group_rev )?.try_into()?;
+
+    if let Some(committed) = status.committed {
+        Err(CoopContentError::AlreadyCommitted {
+            proposal: proposal_id.to_owned(),
+            committed,
+        })?
+    }
+
+    if !group.is_admin( &agent_id ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: status.group_id.to_owned(),
+            agent: agent_id.to_owned(),
+            operation: "commit governance proposals".to_string(),
+        })?
+    }
+
+    if status.approvals.len() < status.quorum {
+        Err(CoopContentError::QuorumNotReached {
+            proposal: proposal_id.to_owned(),
+            approvals: status.approvals.len(),
+            quorum: status.quorum,
+        })?
+    }
+
+    let mut entry = status.proposal.changes.apply( &group );
+    entry.last_updated = sys_time()?.as_millis() as u64;
+
+    debug!("Committing governance proposal {} to group {}", proposal_id, status.group_id );
+    let updated = update_group( UpdateGroupInput {
+        base: status.proposal.group_rev,
+        entry,
+        require_latest: true,
+        memo: status.proposal.description,
+    })?;
+
+    let commit_tag = GovernanceCommitTag {
+        votes: approving_votes.into_iter()
+            .take( status.quorum )
+            .collect(),
+    };
+
+    create_link( proposal_id, updated.action.to_owned(), LinkTypes::GovernanceCommit, commit_tag.to_link_tag()? )?;
+
+    Ok( updated )
+}
@@ -0,0 +1,291 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    get_group,
+    resolve_group_state,
+};
+
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use hdi_extensions::{
+    trace_origin_root,
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    GroupHandleTag,
+    GroupHandleTransfer,
+    check_group_handle,
+    group_handle_path,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    AsOf,
+    GroupHandleInput,
+};
+
+
+/// A handle link with the deletes that reference it
+struct HandleClaim {
+    create: CreateLink,
+    tag: GroupHandleTag,
+    deletes: Vec<(AgentPubKey, Timestamp)>,
+}
+
+/// Get the revision of a group that was the latest at the given time
+fn group_rev_at(group_id: &ActionHash, timestamp: &Timestamp) -> ExternResult<ActionHash> {
+    Ok(
+        resolve_group_state( group_id, &Some(AsOf::Timestamp(timestamp.to_owned())) )?.0
+            .action_address().to_owned()
+    )
+}
+
+/// Check if an agent was an admin of a group's latest revision at the given time
+fn was_admin_at(group_id: &ActionHash, agent: &AgentPubKey, timestamp: &Timestamp) -> ExternResult<bool> {
+    let group : GroupEntry = must_get( &group_rev_at( group_id, timestamp )? )?.try_into()?;
+
+    Ok( group.is_admin( agent ) )
+}
+
+impl HandleClaim {
+    /// Check if this link was deleted (before the given time) by its author or by an admin of
+    /// the target group's latest revision at the time of the delete
+    ///
+    /// Validation can only check admin authority against the tagged revision, so deletes made
+    /// by former admins are ignored here.
+    fn is_released(&self, before: Option<&Timestamp>) -> ExternResult<bool> {
+        let group_id = match self.create.target_address.clone().into_action_hash() {
+            Some(hash) => hash,
+            None => return Ok(true),
+        };
+
+        for (author, timestamp) in self.deletes.iter() {
+            if before.is_some_and( |before| timestamp >= before ) {
+                continue;
+            }
+
+            if *author == self.create.author || was_admin_at( &group_id, author, timestamp )? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Get the handle links (including deleted ones) with their deletes
+fn handle_claims(handle: &str) -> ExternResult<BTreeMap<ActionHash, HandleClaim>> {
+    let details = get_link_details(
+        group_handle_path( handle ).path_entry_hash()?,
+        LinkTypes::GroupHandle,
+        None,
+        GetOptions::network(),
+    )?;
+    let mut claims = BTreeMap::new();
+
+    for (create_action, deletes) in details.into_inner() {
+        let create = match create_action.action() {
+            Action::CreateLink(create) => create.to_owned(),
+            _ => continue,
+        };
+        let tag = match GroupHandleTag::try_from( create.tag.to_owned() ) {
+            Ok(tag) => tag,
+            Err(err) => {
+                debug!("Ignoring unreadable handle link {}: {:?}", create_action.action_address(), err );
+                continue;
+            },
+        };
+
+        claims.insert( create_action.action_address().to_owned(), HandleClaim {
+            create,
+            tag,
+            deletes: deletes.iter()
+                .map( |delete| (delete.action().author().to_owned(), delete.action().timestamp()) )
+                .collect(),
+        });
+    }
+
+    Ok( claims )
+}
+
+/// The ordering key for a handle link's claim
+///
+/// A transfer inherits the timestamp and address of the original claim so that transfers do not
+/// lose their place in line.  Deeper transfers win over the links they replaced.  A transfer of a
+/// handle that was already released is ordered as a new claim.  Returns `None` for transfers
+/// whose author was not an admin of the previous group's latest revision.
+fn claim_order(
+    addr: &ActionHash,
+    claims: &BTreeMap<ActionHash, HandleClaim>,
+) -> ExternResult<Option<(Timestamp, ActionHash, std::cmp::Reverse<usize>)>> {
+    let claim = match claims.get( addr ) {
+        Some(claim) => claim,
+        None => return Ok(None),
+    };
+    let own_order = ( claim.create.timestamp, addr.to_owned(), std::cmp::Reverse(0) );
+
+    let transfer = match claim.tag.previous.as_ref() {
+        Some(transfer) => transfer,
+        None => return Ok( Some(own_order) ),
+    };
+    let previous = match claims.get( &transfer.link ) {
+        Some(previous) => previous,
+        None => return Ok(None),
+    };
+
+    if previous.is_released( Some(&claim.create.timestamp) )? {
+        return Ok( Some(own_order) );
+    }
+
+    let previous_group_id = trace_origin_root( &transfer.group_rev )?.0;
+
+    if group_rev_at( &previous_group_id, &claim.create.timestamp )? != transfer.group_rev {
+        debug!("Ignoring handle transfer {} made with a stale group revision ({})", addr, transfer.group_rev );
+        return Ok(None);
+    }
+
+    Ok(
+        claim_order( &transfer.link, claims )?
+            .map( |(timestamp, root, depth)| (timestamp, root, std::cmp::Reverse(depth.0 + 1)) )
+    )
+}
+
+/// Get the link that currently holds the given handle
+fn current_handle_link(handle: &str) -> ExternResult<Option<(ActionHash, CreateLink)>> {
+    let claims = handle_claims( handle )?;
+    let mut current = None;
+
+    for (addr, claim) in claims.iter() {
+        if claim.is_released( None )? {
+            continue;
+        }
+
+        if let Some(order) = claim_order( addr, &claims )? {
+            if current.as_ref().is_none_or( |(current_order, _)| order < *current_order ) {
+                current = Some( (order, addr) );
+            }
+        }
+    }
+
+    Ok(
+        current.map( |(_, addr)| (addr.to_owned(), claims[addr].create.to_owned()) )
+    )
+}
+
+/// Get the latest revision of a group that the current agent is an admin of
+fn admin_group_rev(group_id: &ActionHash) -> ExternResult<ActionHash> {
+    let group = get_group( group_id.to_owned() )?;
+
+    if !group.content.is_admin( &agent_id()? ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group_id.to_owned(),
+            agent: agent_id()?,
+            operation: "manage its handle".to_string(),
+        })?
+    }
+
+    Ok( group.action )
+}
+
+fn check_handle(handle: &str) -> ExternResult<()> {
+    check_group_handle( handle )
+        .map_err( |message| guest_error!(message) )
+}
+
+
+/// Claim an unused handle for a group
+#[hdk_extern]
+pub fn claim_group_handle(input: GroupHandleInput) -> ExternResult<ActionHash> {
+    check_handle( &input.handle )?;
+
+    if let Some((_, current)) = current_handle_link( &input.handle )? {
+        Err(guest_error!(format!(
+            "Handle '{}' is already claimed by group '{}'",
+            input.handle, current.target_address,
+        )))?
+    }
+
+    let group_rev = admin_group_rev( &input.group_id )?;
+    let tag = GroupHandleTag {
+        handle: input.handle.to_owned(),
+        group_rev,
+        previous: None,
+    };
+    debug!("Claiming handle '{}' for group: {}", input.handle, input.group_id );
+
+    create_link(
+        group_handle_path( &input.handle ).path_entry_hash()?,
+        input.group_id,
+        LinkTypes::GroupHandle,
+        tag.to_link_tag()?,
+    )
+}
+
+
+/// Get the group ID that currently holds a handle
+#[hdk_extern]
+pub fn resolve_group_handle(handle: String) -> ExternResult<ActionHash> {
+    check_handle( &handle )?;
+
+    current_handle_link( &handle )?
+        .and_then( |(_, current)| current.target_address.into_action_hash() )
+        .ok_or(guest_error!(format!("Handle '{}' is not claimed", handle )))
+}
+
+
+/// Transfer a handle to another group
+///
+/// The agent must be an admin of both the current and the receiving group.
+#[hdk_extern]
+pub fn transfer_group_handle(input: GroupHandleInput) -> ExternResult<ActionHash> {
+    check_handle( &input.handle )?;
+
+    let (current_addr, current) = current_handle_link( &input.handle )?
+        .ok_or(guest_error!(format!("Handle '{}' is not claimed", input.handle )))?;
+    let current_group_id = current.target_address.to_owned().into_action_hash()
+        .ok_or(guest_error!(format!("Handle '{}' target is not a group ID", input.handle )))?;
+
+    let tag = GroupHandleTag {
+        handle: input.handle.to_owned(),
+        group_rev: admin_group_rev( &input.group_id )?,
+        previous: Some(GroupHandleTransfer {
+            link: current_addr.to_owned(),
+            group_rev: admin_group_rev( &current_group_id )?,
+        }),
+    };
+    debug!("Transferring handle '{}' from group {} to {}", input.handle, current_group_id, input.group_id );
+
+    let action_hash = create_link(
+        group_handle_path( &input.handle ).path_entry_hash()?,
+        input.group_id,
+        LinkTypes::GroupHandle,
+        tag.to_link_tag()?,
+    )?;
+    delete_link( current_addr )?;
+
+    Ok( action_hash )
+}
+
+
+/// Release a handle so that it can be claimed again
+#[hdk_extern]
+pub fn release_group_handle(handle: String) -> ExternResult<ActionHash> {
+    check_handle( &handle )?;
+
+    let (current_addr, current) = current_handle_link( &handle )?
+        .ok_or(guest_error!(format!("Handle '{}' is not claimed", handle )))?;
+    let group_id = current.target_address.to_owned().into_action_hash()
+        .ok_or(guest_error!(format!("Handle '{}' target is not a group ID", handle )))?;
+
+    admin_group_rev( &group_id )?;
+    debug!("Releasing handle '{}' from group: {}", handle, group_id );
+
+    delete_link( current_addr )
+}
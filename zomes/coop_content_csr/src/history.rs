@@ -0,0 +1,99 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    check_group_id,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+    must_get_record_details,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+};
+use coop_content_sdk::{
+    create_link_input,
+    GroupRevisionHistory,
+};
+
+
+/// Get every revision of a group (including forks) with the revision it updated
+pub fn group_revisions(group_id: &ActionHash) -> ExternResult<Vec<(ActionHash, Option<ActionHash>)>> {
+    let mut revisions = vec![];
+    let mut queue = vec![ (group_id.to_owned(), None) ];
+
+    while let Some((addr, previous)) = queue.pop() {
+        for update in must_get_record_details( &addr )?.updates {
+            queue.push( (update.hashed.hash, Some(addr.to_owned())) );
+        }
+
+        revisions.push( (addr, previous) );
+    }
+
+    Ok( revisions )
+}
+
+/// Get the earliest memo that the revision's author linked to it
+fn revision_memo(group_rev: &ActionHash, author: &AgentPubKey) -> ExternResult<Option<String>> {
+    let links = get_links(
+        create_link_input(
+            group_rev,
+            &LinkTypes::GroupRevisionMemo,
+            &None::<()>,
+        )?
+    )?;
+
+    Ok(
+        links.into_iter()
+            .filter( |link| link.author == *author )
+            .min_by_key( |link| link.timestamp )
+            .and_then( |link| String::from_utf8( link.tag.into_inner() ).ok() )
+    )
+}
+
+
+/// Get every revision of a group ordered by time, with what changed in each and its memo
+#[hdk_extern]
+pub fn get_group_history(group_id: ActionHash) -> ExternResult<Vec<GroupRevisionHistory>> {
+    check_group_id( &group_id )?;
+
+    let mut history = vec![];
+
+    for (group_rev, previous) in group_revisions( &group_id )? {
+        let record = must_get( &group_rev )?;
+        let author = record.action().author().to_owned();
+        let timestamp = record.action().timestamp();
+        let group : GroupEntry = record.try_into()?;
+        let prev_group = match &previous {
+            Some(prev_rev) => must_get( prev_rev )?.try_into()?,
+            // The create is compared to an empty group so that everything shows as added
+            None => GroupEntry {
+                admins: vec![],
+                members: vec![],
+                deleted: None,
+                content_types: None,
+                reviewers: None,
+                governance_quorum: None,
+                published_at: group.published_at,
+                last_updated: group.last_updated,
+                metadata: Default::default(),
+            },
+        };
+
+        history.push( GroupRevisionHistory {
+            contributors_diff: prev_group.contributors_diff( &group ),
+            metadata_changes: prev_group.metadata_changes( &group ),
+            memo: revision_memo( &group_rev, &author )?,
+            group_rev,
+            previous,
+            author,
+            timestamp,
+        });
+    }
+
+    history.sort_by_key( |revision| revision.timestamp );
+
+    Ok( history )
+}
@@ -0,0 +1,177 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    Entity,
+    get_group,
+    update_group,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+    follow_evolutions,
+};
+use hdi_extensions::{
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    CoopContentError,
+};
+use coop_content_sdk::{
+    create_link_input,
+    CreateJoinRequestInput,
+    UpdateGroupInput,
+    GroupJoinRequest,
+};
+
+
+fn join_request_links(group_id: &ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(
+        create_link_input(
+            group_id,
+            &LinkTypes::GroupJoinRequest,
+            &None::<()>,
+        )?
+    )
+}
+
+/// Get the join requests for a group that have not been handled yet
+///
+/// A request is handled once any group revision made after the request includes the agent.
+fn pending_join_requests(group_id: &ActionHash) -> ExternResult<Vec<GroupJoinRequest>> {
+    let mut revisions = vec![];
+    for addr in follow_evolutions( group_id )? {
+        let record = must_get( &addr )?;
+        let timestamp = record.action().timestamp();
+        let group : GroupEntry = record.try_into()?;
+
+        revisions.push( (timestamp, group) );
+    }
+
+    let mut requests : Vec<GroupJoinRequest> = vec![];
+    for link in join_request_links( group_id )? {
+        let agent = match link.target.to_owned().into_agent_pub_key() {
+            Some(agent) => agent,
+            None => continue,
+        };
+
+        let handled = revisions.iter()
+            .any( |(timestamp, group)| *timestamp > link.timestamp && group.is_contributor( &agent ) );
+
+        if handled || requests.iter().any( |request| request.agent == agent ) {
+            continue;
+        }
+
+        requests.push( GroupJoinRequest {
+            id: link.create_link_hash,
+            group_id: group_id.to_owned(),
+            agent,
+            message: String::from_utf8( link.tag.into_inner() ).ok()
+                .filter( |message| !message.is_empty() ),
+            timestamp: link.timestamp,
+        });
+    }
+
+    Ok( requests )
+}
+
+
+/// Request to join a group
+///
+/// Returns the existing request if the agent already has a pending request for the group.
+#[hdk_extern]
+pub fn request_to_join_group(input: CreateJoinRequestInput) -> ExternResult<ActionHash> {
+    let agent_id = agent_id()?;
+    let group = get_group( input.group_id.to_owned() )?;
+
+    if group.content.is_contributor( &agent_id ) {
+        Err(guest_error!(format!(
+            "Agent is already a contributor in group '{}'",
+            input.group_id,
+        )))?
+    }
+
+    if let Some(request) = pending_join_requests( &input.group_id )?.into_iter()
+        .find( |request| request.agent == agent_id ) {
+        return Ok( request.id );
+    }
+
+    debug!("Requesting to join group: {}", input.group_id );
+    create_link(
+        input.group_id,
+        agent_id,
+        LinkTypes::GroupJoinRequest,
+        LinkTag::new( input.message.unwrap_or_default().into_bytes() ),
+    )
+}
+
+
+/// Get the pending join requests for a group
+#[hdk_extern]
+pub fn get_group_join_requests(group_id: ActionHash) -> ExternResult<Vec<GroupJoinRequest>> {
+    pending_join_requests( &group_id )
+}
+
+
+/// Approve a join request by adding the requesting agent to the group's members
+#[hdk_extern]
+pub fn approve_group_join_request(request_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
+    let create = match must_get_action( request_id.to_owned() )?.action().to_owned() {
+        Action::CreateLink(action) => action,
+        _ => Err(guest_error!(format!("Action '{}' is not a join request", request_id )))?,
+    };
+    let (group_id, agent) = match (
+        create.base_address.into_action_hash(),
+        create.target_address.into_agent_pub_key(),
+    ) {
+        (Some(group_id), Some(agent)) => (group_id, agent),
+        _ => Err(guest_error!(format!("Action '{}' is not a join request", request_id )))?,
+    };
+
+    if !pending_join_requests( &group_id )?.iter().any( |request| request.agent == agent ) {
+        Err(guest_error!(format!("Join request '{}' is not pending", request_id )))?
+    }
+
+    let group = get_group( group_id )?;
+
+    if !group.content.is_admin( &agent_id()? ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: group.id.to_owned(),
+            agent: agent_id()?,
+            operation: "approve join requests".to_string(),
+        })?
+    }
+
+    let mut entry = group.content;
+    entry.members.push( agent );
+    entry.last_updated = sys_time()?.as_millis() as u64;
+
+    update_group( UpdateGroupInput {
+        base: group.action,
+        entry,
+        require_latest: true,
+        memo: None,
+    })
+}
+
+
+/// Cancel the current agent's join requests for a group
+#[hdk_extern]
+pub fn cancel_group_join_request(group_id: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let agent_id = agent_id()?;
+    let mut deleted = vec![];
+
+    for link in join_request_links( &group_id )? {
+        if link.author == agent_id {
+            deleted.push( delete_link( link.create_link_hash )? );
+        }
+    }
+    debug!("Cancelled {} join requests for group: {}", deleted.len(), group_id );
+
+    Ok( deleted )
+}
@@ -1,11 +1,29 @@
 mod scoped_types;
+mod archive;
+mod audit;
+mod forks;
+mod stats;
+mod content_types;
+mod threads;
+mod explain;
+mod history;
+mod directory;
+mod handle;
+mod join_requests;
+mod signals;
+mod membership;
+mod locks;
+mod review;
+mod governance;
 
 pub use coop_content::hdi;
 pub use coop_content::hdi_extensions;
 pub use coop_content_sdk::hdk;
 pub use coop_content_sdk::hdk_extensions;
 
-use std::collections::HashMap;
+use std::collections::{
+    BTreeMap, HashMap,
+};
 use lazy_static::lazy_static;
 use hdk::prelude::*;
 use hdk_extensions::{
@@ -14,13 +32,21 @@ use hdk_extensions::{
     exists,
     resolve_action_addr,
     follow_evolutions,
+    follow_evolutions_selector,
     follow_evolutions_using_authorities_with_exceptions,
+    must_get_record_details,
     // Input Structs
-    UpdateEntryInput,
     GetLinksInput,
 };
 pub use scoped_types::entry_traits::*;
+use scoped_types::{
+    dedupe_links,
+    tag_without_revision,
+    parse_contribution_tag,
+    parse_contribution_update_tag,
+};
 use hdi_extensions::{
+    trace_origin,
     trace_origin_root,
     summon_create_link_action,
     ScopedTypeConnector,
@@ -35,17 +61,36 @@ use coop_content::{
     GroupEntry,
     ContributionsAnchorEntry,
     ArchivedContributionsAnchorEntry,
+    ContributionAnchors,
     ContributionAnchorTypes,
+    CoopContentError,
+    CONTRIBUTION_TAG_REV_SEPARATOR,
 };
 use coop_content_sdk::{
     create_link_input,
 
+    AsOf,
+
     // Input Structs
     GroupAuthInput,
+    GetGroupInput,
+    GetGroupActivityInput,
+    GetAgentContributionsInput,
     GetAllGroupContentInput,
+    GetAllGroupContentShortcutsInput,
     GetGroupContentInput,
     CreateContributionLinkInput,
     CreateContributionUpdateLinkInput,
+    UpdateGroupInput,
+
+    // Output Structs
+    Entity,
+    BatchResult,
+    GroupUpdatePlan,
+    GroupUpdateStep,
+    GroupActivity,
+    AgentContributions,
+    ContributionTargets,
 };
 
 
@@ -78,35 +123,74 @@ where
 #[hdk_extern]
 fn init(_: ()) -> ExternResult<InitCallbackResult> {
     debug!("'{}' init", *ZOME_NAME );
+    signals::grant_remote_signal_capability()?;
+
     Ok(InitCallbackResult::Pass)
 }
 
 
 #[hdk_extern]
 fn whoami(_: ()) -> ExternResult<AgentInfo> {
-    Ok( agent_info()? )
+    agent_info()
 }
 
 
 
-/// The context and content of a specific entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Entity<T> {
-    /// The address of the original create action
-    pub id: ActionHash,
+/// Build a group entity from a revision record and the revisions before it (oldest first)
+fn group_entity(
+    record: Record,
+    prior_revisions: Vec<ActionHash>,
+    is_latest: bool,
+    forks: Vec<ActionHash>,
+) -> ExternResult<Entity<GroupEntry>> {
+    let action = record.action_address().to_owned();
 
-    /// The create/update action of the current entry
-    pub action: ActionHash,
+    Ok(Entity {
+        id: prior_revisions.first().cloned().unwrap_or( action.to_owned() ),
+        address: record.action().entry_hash()
+            .ok_or(guest_error!("Record does not have an entry".to_string()))?
+            .to_owned(),
+        ctype: "group".to_string(),
+        author: record.action().author().to_owned(),
+        timestamp: record.action().timestamp(),
+        revision: prior_revisions.len(),
+        content: GroupEntry::try_from_record( &record )?,
+        action,
+        is_latest,
+        prior_revisions,
+        forks,
+    })
+}
 
-    /// The address of the current entry
-    pub address: EntryHash,
+/// Get the revisions before the given group revision, oldest first
+fn prior_group_revisions(group_rev: &ActionHash) -> ExternResult<Vec<ActionHash>> {
+    Ok(
+        trace_origin( group_rev )?.into_iter()
+            .skip( 1 )
+            .rev()
+            .map( |(addr, _)| addr )
+            .collect()
+    )
+}
+
+/// Check that the given action hash is a group ID rather than a later revision
+fn check_group_id(group_id: &ActionHash) -> ExternResult<()> {
+    if *group_id != trace_origin_root( group_id )?.0 {
+        Err(CoopContentError::NotAnId {
+            address: group_id.to_owned(),
+        })?
+    }
 
-    #[serde(rename = "type")]
-    /// An identifier for the content's type and structure
-    pub ctype: String,
+    Ok(())
+}
 
-    /// The entity's current value
-    pub content: T,
+/// Get the latest revision of a group (the group ID when it has no updates)
+fn latest_group_rev(group_id: &ActionHash) -> ExternResult<ActionHash> {
+    Ok(
+        follow_evolutions( group_id )?.last()
+            .cloned()
+            .unwrap_or( group_id.to_owned() )
+    )
 }
 
 
@@ -115,7 +199,6 @@ pub struct Entity<T> {
 pub fn create_group(group: GroupEntry) -> ExternResult<Entity<GroupEntry>> {
     debug!("Creating new group entry: {:#?}", group );
     let action_hash = create_entry( group.to_input() )?;
-    let entry_hash = hash_entry( &group )?;
     let agent_id = agent_id()?;
 
     for pubkey in group.contributors() {
@@ -135,13 +218,7 @@ pub fn create_group(group: GroupEntry) -> ExternResult<Entity<GroupEntry>> {
 
     create_link( agent_id, action_hash.clone(), LinkTypes::Group, () )?;
 
-    Ok(Entity {
-        id: action_hash.clone(),
-        action: action_hash,
-        address: entry_hash,
-        ctype: "group".to_string(),
-        content: group,
-    })
+    group_entity( must_get( &action_hash )?, vec![], true, vec![] )
 }
 
 
@@ -236,120 +313,262 @@ pub fn remove_group_links(group_id: ActionHash) -> ExternResult<Vec<ActionHash>>
 
 
 
+/// Compute the contributor changes and links for a group update without committing anything
+fn group_update_plan(input: &UpdateGroupInput) -> ExternResult<GroupUpdatePlan> {
+    let group_id = trace_origin_root( &input.base )?.0;
+    let prev_group : GroupEntry = must_get( &input.base )?.try_into()?;
+    let contributors_diff = prev_group.contributors_diff( &input.entry );
+    let latest = follow_evolutions( &input.base )?.last()
+        .cloned()
+        .unwrap_or( input.base.to_owned() );
+    let mut steps = vec![];
+
+    for anchor in GroupEntry::group_auth_archive_anchor_hashes( &input.base )? {
+        steps.push( GroupUpdateStep::CarryArchive { anchor } );
+    }
+
+    for agent in contributors_diff.removed.iter() {
+        steps.push( GroupUpdateStep::ArchiveMember {
+            agent: agent.to_owned(),
+            links: archive::archive_link_count( &group_id, agent )?,
+        });
+    }
+
+    for agent in contributors_diff.added.iter() {
+        steps.push( GroupUpdateStep::AddMember {
+            agent: agent.to_owned(),
+            anchor: hash_entry( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?,
+        });
+    }
+
+    for agent in contributors_diff.intersection.iter() {
+        steps.push( GroupUpdateStep::KeepMember {
+            agent: agent.to_owned(),
+            anchor: hash_entry( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?,
+        });
+    }
+
+    Ok(GroupUpdatePlan {
+        group_id,
+        base: input.base.to_owned(),
+        latest,
+        contributors_diff,
+        steps,
+    })
+}
+
+
 /// Update a group
+///
+/// When `require_latest` is set, the update is rejected with
+/// [`CoopContentError::StaleRevision`] if the base revision has already been updated.  An
+/// optional memo is linked from the new revision.
 #[hdk_extern]
-pub fn update_group(input: UpdateEntryInput<GroupEntry>) -> ExternResult<Entity<GroupEntry>> {
+pub fn update_group(input: UpdateGroupInput) -> ExternResult<Entity<GroupEntry>> {
     debug!("Update group action: {}", input.base );
-    let group_id = trace_origin_root( &input.base )?.0;
+    let agent_id = agent_id()?;
     let prev_group : GroupEntry = must_get( &input.base )?.try_into()?;
-    let contributors_diff = prev_group.contributors_diff( &input.entry );
+    let is_self_removal = !prev_group.is_admin( &agent_id );
+
+    if is_self_removal && !prev_group.is_self_removal( &input.entry, &agent_id ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: trace_origin_root( &input.base )?.0,
+            agent: agent_id.to_owned(),
+            operation: "update it; members can only remove themselves".to_string(),
+        })?
+    }
 
-    let action_hash = update_entry( input.base.to_owned(), input.entry.to_input() )?;
-    let entry_hash = hash_entry( &input.entry )?;
+    let plan = group_update_plan( &input )?;
 
-    let archive_links = get_links(
-        create_link_input(
-            &input.base,
-            &LinkTypes::GroupAuthArchive,
-            &None::<()>,
-        )?
-    )?;
-    for link in archive_links {
-        create_link( action_hash.to_owned(), link.target, LinkTypes::GroupAuthArchive, link.tag )?;
+    if input.require_latest && plan.is_stale() {
+        Err(CoopContentError::StaleRevision {
+            base: plan.base,
+            latest: plan.latest,
+        })?
     }
 
-    for pubkey in contributors_diff.removed {
-        debug!("Removed Agent: {}", pubkey );
-        let anchor = ContributionsAnchorEntry( group_id.to_owned(), pubkey.to_owned() );
-        let anchor_hash = hash_entry( &anchor )?;
-        let archive_anchor = ArchivedContributionsAnchorEntry::new( action_hash.to_owned(), pubkey.to_owned() );
-        let archive_anchor_hash = hash_entry( &archive_anchor )?;
+    // Members removing themselves can only link and copy to their own archive anchor; the other
+    // anchors are carried over from the base revision
+    let group_id = plan.group_id;
+    let action_hash = update_entry( input.base.to_owned(), input.entry.to_input() )?;
 
-        create_if_not_exists( &archive_anchor )?;
-        create_link( action_hash.to_owned(), archive_anchor_hash.to_owned(), LinkTypes::GroupAuthArchive, () )?;
+    if let Some(memo) = input.memo {
+        create_link( action_hash.to_owned(), group_id.to_owned(), LinkTypes::GroupRevisionMemo, memo.into_bytes() )?;
+    }
 
-        let creates = get_links(
-            create_link_input(
-                &anchor_hash,
-                &LinkTypes::Contribution,
-                &None::<()>,
-            )?
-        )?;
-        let updates = get_links(
-            create_link_input(
-                &anchor_hash,
-                &LinkTypes::ContributionUpdate,
-                &None::<()>,
-            )?
-        )?;
+    for step in plan.steps {
+        match step {
+            GroupUpdateStep::CarryArchive { .. } | GroupUpdateStep::KeepMember { .. } if is_self_removal => {},
+            GroupUpdateStep::CarryArchive { anchor } => {
+                create_link( action_hash.to_owned(), anchor, LinkTypes::GroupAuthArchive, () )?;
+            },
+            GroupUpdateStep::ArchiveMember { agent, .. } => {
+                debug!("Removed Agent: {}", agent );
+                let progress = archive::start_archive( &group_id, &action_hash, &agent )?;
 
-        debug!("Copying {} creates for auth archive: {}", creates.len(), pubkey );
-        for link in creates {
-            create_link( archive_anchor_hash.to_owned(), link.target, LinkTypes::Contribution, link.tag )?;
-        }
+                if !progress.is_complete() {
+                    debug!("Archive copy for {} is incomplete ({}/{}); finish with 'continue_archive'", agent, progress.copied, progress.total );
+                }
+            },
+            GroupUpdateStep::AddMember { agent, anchor } => {
+                debug!("Added Agent: {}", agent );
+                create_if_not_exists( &ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() ) )?;
+                create_link( action_hash.to_owned(), anchor, LinkTypes::GroupAuth, () )?;
 
-        debug!("Copying {} updates for auth archive: {}", updates.len(), pubkey );
-        for link in updates {
-            create_link( archive_anchor_hash.to_owned(), link.target, LinkTypes::ContributionUpdate, link.tag )?;
+                let invite_anchor_hash = Path::from(vec![
+                    Component::from( format!("{}:invitations", agent ).as_bytes().to_vec() )
+                ]).path_entry_hash()?;
+
+                // Invite member to group
+                create_link( invite_anchor_hash, group_id.clone(), LinkTypes::GroupInvite, () )?;
+            },
+            GroupUpdateStep::KeepMember { agent, anchor } => {
+                debug!("Unchanged Agent: {}", agent );
+                create_link( action_hash.to_owned(), anchor, LinkTypes::GroupAuth, () )?;
+            },
         }
     }
 
-    for pubkey in contributors_diff.added {
-        debug!("Added Agent: {}", pubkey );
-        let anchor = ContributionsAnchorEntry( group_id.to_owned(), pubkey.to_owned() );
-        let anchor_hash = hash_entry( &anchor )?;
-        create_if_not_exists( &anchor )?;
-        create_link( action_hash.to_owned(), anchor_hash, LinkTypes::GroupAuth, () )?;
+    let mut prior_revisions = prior_group_revisions( &input.base )?;
+    prior_revisions.push( input.base );
 
-        let invite_anchor_hash = Path::from(vec![
-            Component::from( format!("{}:invitations", pubkey ).as_bytes().to_vec() )
-        ]).path_entry_hash()?;
+    group_entity( must_get( &action_hash )?, prior_revisions, true, vec![] )
+}
 
-        // Invite member to group
-        create_link( invite_anchor_hash, group_id.clone(), LinkTypes::GroupInvite, () )?;
-    }
 
-    for pubkey in contributors_diff.intersection {
-        debug!("Unchanged Agent: {}", pubkey );
-        let anchor = ContributionsAnchorEntry( group_id.to_owned(), pubkey.to_owned() );
-        let anchor_hash = hash_entry( &anchor )?;
-        create_link( action_hash.to_owned(), anchor_hash, LinkTypes::GroupAuth, () )?;
+/// Get the contributor changes and links that a group update would create, without committing
+/// anything
+#[hdk_extern]
+pub fn plan_group_update(input: UpdateGroupInput) -> ExternResult<GroupUpdatePlan> {
+    group_update_plan( &input )
+}
+
+
+/// Leave a group as a member
+///
+/// Creates a group update whose only change is removing the current agent from the members list.
+/// The agent's contributions are copied to their archive anchor the same way as a member removed by
+/// an admin (the rest of a large archive is copied with `continue_archive`) and the agent's own
+/// group link is removed.
+#[hdk_extern]
+pub fn leave_group(group_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
+    let agent_id = agent_id()?;
+    let group = get_group( group_id.to_owned() )?;
+
+    // Admins must be removed with a group update
+    if !group.content.is_member( &agent_id ) {
+        Err(CoopContentError::NotAMember {
+            group_id: group_id.to_owned(),
+            agent: agent_id.to_owned(),
+        })?
     }
 
-    Ok(Entity {
-        id: group_id,
-        action: action_hash,
-        address: entry_hash,
-        ctype: "group".to_string(),
-        content: input.entry,
-    })
+    let mut entry = group.content;
+    entry.members.retain( |member| *member != agent_id );
+    entry.last_updated = sys_time()?.as_millis() as u64;
+    debug!("Leaving group: {}", group_id );
+
+    let updated = update_group( UpdateGroupInput {
+        base: group.action,
+        entry,
+        require_latest: true,
+        memo: None,
+    })?;
+
+    remove_group_links( group_id )?;
+
+    Ok( updated )
+}
+
+
+/// Resolve the group revision record and the contribution link cutoff for a point in a group's
+/// history
+///
+/// When `as_of` is `None`, the latest revision is returned without a cutoff.  For a specific
+/// revision, the cutoff is the time that revision was first replaced.
+fn resolve_group_state(
+    group_id: &ActionHash,
+    as_of: &Option<AsOf>,
+) -> ExternResult<(Record, Option<Timestamp>)> {
+    Ok(
+        match as_of {
+            None => {
+                ( must_get( &latest_group_rev( group_id )? )?, None )
+            },
+            Some(AsOf::Revision(group_rev)) => {
+                if *group_id != trace_origin_root( group_rev )?.0 {
+                    Err(CoopContentError::NotARevision {
+                        id: group_id.to_owned(),
+                        address: group_rev.to_owned(),
+                    })?
+                }
+
+                let cutoff = must_get_record_details( group_rev )?.updates.iter()
+                    .map(|signed_action| signed_action.action().timestamp() )
+                    .min();
+
+                ( must_get( group_rev )?, cutoff )
+            },
+            Some(AsOf::Timestamp(timestamp)) => {
+                let mut record = None;
+
+                for addr in follow_evolutions( group_id )? {
+                    let rev_record = must_get( &addr )?;
+
+                    if rev_record.action().timestamp() > *timestamp {
+                        break;
+                    }
+
+                    record = Some(rev_record);
+                }
+
+                (
+                    record.ok_or(guest_error!(format!(
+                        "Group '{}' did not exist at {}",
+                        group_id, timestamp,
+                    )))?,
+                    Some(timestamp.to_owned())
+                )
+            },
+        }
+    )
 }
 
 
 /// Get the current state for the given group ID
 #[hdk_extern]
 pub fn get_group(group_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
-    if group_id != trace_origin_root( &group_id )?.0 {
-        Err(guest_error!(format!(
-            "Action hash '{}' is not an ID",
-            group_id,
-        )))?
-    }
-    debug!("Get latest group entry: {}", group_id );
-    let latest_addr = follow_evolutions( &group_id )?.last().unwrap().to_owned();
-    let record = must_get( &latest_addr )?;
+    get_group_as_of( GetGroupInput {
+        group_id,
+        as_of: None,
+    })
+}
 
-    let group = GroupEntry::try_from_record( &record )?;
 
-    Ok(Entity {
-        id: group_id,
-        action: record.action_address().to_owned(),
-        address: record.action().entry_hash()
-            .ok_or(guest_error!("Record does not have an entry".to_string()))?
-            .to_owned(),
-        ctype: "group".to_string(),
-        content: group,
-    })
+/// Get the state for the given group ID at a point in its history
+#[hdk_extern]
+pub fn get_group_as_of(input: GetGroupInput) -> ExternResult<Entity<GroupEntry>> {
+    let group_id = input.group_id;
+
+    check_group_id( &group_id )?;
+    debug!("Get group entry: {} (as of {:?})", group_id, input.as_of );
+
+    match input.as_of {
+        None => {
+            let mut prior_revisions = follow_evolutions( &group_id )?;
+            let latest_addr = prior_revisions.pop().unwrap_or( group_id.to_owned() );
+            let forks = forks::unmerged_forks( &group_id, &latest_addr )?;
+
+            group_entity( must_get( &latest_addr )?, prior_revisions, true, forks )
+        },
+        Some(as_of) => {
+            let (record, _) = resolve_group_state( &group_id, &Some(as_of) )?;
+            let prior_revisions = prior_group_revisions( record.action_address() )?;
+            let is_latest = must_get_record_details( record.action_address() )?.updates.is_empty();
+
+            group_entity( record, prior_revisions, is_latest, vec![] )
+        },
+    }
 }
 
 
@@ -357,9 +576,59 @@ pub fn get_group(group_id: ActionHash) -> ExternResult<Entity<GroupEntry>> {
 #[hdk_extern]
 pub fn get_all_group_content_targets(input: GetAllGroupContentInput) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
     match input.full_trace {
-        None | Some(false) => get_all_group_content_targets_shortcuts( (input.group_id, input.content_type, input.content_base) ),
-        Some(true) => get_all_group_content_targets_full_trace( (input.group_id, input.content_type, input.content_base) ),
+        None | Some(false) => group_content_targets_shortcuts( input.group_id, input.content_type, input.content_base, input.as_of, input.include_proposals ),
+        Some(true) => group_content_targets_full_trace( input.group_id, input.content_type, input.content_base, input.as_of, input.include_proposals ),
+    }
+}
+
+
+/// Collect the chain of content evolutions forward filtering authorized updates with exceptions
+///
+/// Updates made after the optional cutoff are ignored.
+fn follow_group_content_evolutions(
+    action_address: &ActionHash,
+    authors: &Vec<AgentPubKey>,
+    exceptions: &Vec<ActionHash>,
+    cutoff: &Option<Timestamp>,
+) -> ExternResult<Vec<ActionHash>> {
+    let cutoff = match cutoff {
+        Some(cutoff) => cutoff,
+        None => return follow_evolutions_using_authorities_with_exceptions( action_address, authors, exceptions ),
+    };
+
+    follow_evolutions_selector( action_address, |updates| {
+        Ok(
+            updates.into_iter()
+                .filter(|sa| sa.action().timestamp() <= *cutoff )
+                .filter(|sa| authors.contains( sa.action().author() ) || exceptions.contains( sa.action_address() ) )
+                .min_by_key(|sa| sa.action().timestamp() )
+                .map(|sa| sa.hashed.hash )
+        )
+    })
+}
+
+
+/// Collect the archived content updates that are exceptions to the group's current authorities
+fn group_archived_updates(
+    group_rev: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+    let mut archived_updates : Vec<ActionHash> = vec![];
+    let auth_archive_anchors = GroupEntry::group_auth_archive_anchor_hashes( group_rev )?;
+
+    debug!("Found {} auth archives for group rev '{}'", auth_archive_anchors.len(), group_rev );
+    for auth_archive_addr in auth_archive_anchors.iter() {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( auth_archive_addr )?.try_into()?;
+
+        let archive_updates = anchor.update_targets()?;
+        let update_actions : Vec<ActionHash> = archive_updates.iter()
+            .cloned()
+            .filter_map(|target| target.into_action_hash() )
+            .collect();
+        debug!("Removed {}/{} archive updates because they were not ActionHash targets", archive_updates.len() - update_actions.len(), archive_updates.len() );
+        archived_updates.extend( update_actions );
     }
+
+    Ok( archived_updates )
 }
 
 
@@ -368,14 +637,23 @@ pub fn get_all_group_content_targets(input: GetAllGroupContentInput) -> ExternRe
 pub fn get_all_group_content_targets_full_trace(
     (group_id, content_type, content_base): (ActionHash, Option<String>, Option<String>)
 ) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
-    debug!("Get latest group content: {}", group_id );
-    let latest_addr = follow_evolutions( &group_id )?.last().unwrap().to_owned();
-    let record = must_get( &latest_addr )?;
+    group_content_targets_full_trace( group_id, content_type, content_base, None, false )
+}
+
+
+fn group_content_targets_full_trace(
+    group_id: ActionHash,
+    content_type: Option<String>,
+    content_base: Option<String>,
+    as_of: Option<AsOf>,
+    include_proposals: bool,
+) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
+    debug!("Get group content: {} (as of {:?})", group_id, as_of );
+    let (record, cutoff) = resolve_group_state( &group_id, &as_of )?;
     let group_rev = record.action_address().to_owned();
     let group : GroupEntry = record.try_into()?;
 
     let mut content_creates = vec![];
-    let mut archived_updates : Vec<ActionHash> = vec![];
 
     let auth_archive_anchors = GroupEntry::group_auth_archive_anchor_hashes( &group_rev )?;
 
@@ -383,22 +661,18 @@ pub fn get_all_group_content_targets_full_trace(
     for auth_archive_addr in auth_archive_anchors.iter() {
         let anchor : ArchivedContributionsAnchorEntry = must_get( auth_archive_addr )?.try_into()?;
         content_creates.extend( anchor.create_targets( content_type.clone(), content_base.clone() )? );
-
-        let archive_updates = anchor.update_targets()?;
-        let update_actions : Vec<ActionHash> = archive_updates.iter()
-            .cloned()
-            .filter_map(|target| target.into_action_hash() )
-            .collect();
-        debug!("Removed {}/{} archive updates because they were not ActionHash targets", archive_updates.len() - update_actions.len(), archive_updates.len() );
-        archived_updates.extend( update_actions );
     }
 
+    let mut archived_updates = group_archived_updates( &group_rev )?;
+    let (authors, reviewed_updates) = review::content_authorities( &group, &group_rev, &cutoff, include_proposals )?;
+    archived_updates.extend( reviewed_updates );
+
     let group_auth_anchors = GroupEntry::group_auth_anchor_hashes( &group_rev )?;
 
     debug!("Found {} current contributors for group rev '{}'", group_auth_anchors.len(), group_rev );
     for auth_anchor_addr in group_auth_anchors.iter() {
         let anchor : ContributionsAnchorEntry = must_get( auth_anchor_addr )?.try_into()?;
-        let content_targets = anchor.create_targets( content_type.clone(), content_base.clone() )?;
+        let content_targets = anchor.create_targets( content_type.clone(), content_base.clone(), &cutoff )?;
         debug!("Found {} content links for group contributor '{}'", content_targets.len(), anchor.1 );
         content_creates.extend( content_targets );
     }
@@ -408,10 +682,10 @@ pub fn get_all_group_content_targets_full_trace(
     for content_addr in content_creates {
         match content_addr.clone().into_action_hash() {
             Some(addr) => {
-                let evolutions = follow_evolutions_using_authorities_with_exceptions( &addr, &group.contributors(), &archived_updates )?;
+                let evolutions = follow_group_content_evolutions( &addr, &authors, &archived_updates, &cutoff )?;
                 targets.push((
                     content_addr,
-                    evolutions.last().unwrap().to_owned().into()
+                    evolutions.last().cloned().unwrap_or( addr ).into()
                 ));
             },
             None => continue,
@@ -443,9 +717,19 @@ fn follow_update_map(
 pub fn follow_all_group_content_evolutions_shortcuts(
     (group_id, content_type, content_base): (ActionHash, Option<String>, Option<String>)
 ) -> ExternResult<Vec<(AnyLinkableHash, Vec<AnyLinkableHash>)>> {
-    debug!("Get latest group content: {}", group_id );
-    let latest_addr = follow_evolutions( &group_id )?.last().unwrap().to_owned();
-    let record = must_get( &latest_addr )?;
+    group_content_evolutions_shortcuts( group_id, content_type, content_base, None, false )
+}
+
+
+fn group_content_evolutions_shortcuts(
+    group_id: ActionHash,
+    content_type: Option<String>,
+    content_base: Option<String>,
+    as_of: Option<AsOf>,
+    include_proposals: bool,
+) -> ExternResult<Vec<(AnyLinkableHash, Vec<AnyLinkableHash>)>> {
+    debug!("Get group content: {} (as of {:?})", group_id, as_of );
+    let (record, cutoff) = resolve_group_state( &group_id, &as_of )?;
     let group_rev = record.action_address().to_owned();
 
     let mut targets = vec![];
@@ -476,17 +760,24 @@ pub fn follow_all_group_content_evolutions_shortcuts(
         let anchor : ContributionsAnchorEntry = must_get( auth_anchor_addr )?.try_into()?;
         debug!("Auth anchor: {:#?}", anchor );
 
-        let content_ids = anchor.create_targets( content_type.clone(), content_base.clone() )?;
+        let content_ids = anchor.create_targets( content_type.clone(), content_base.clone(), &cutoff )?;
         debug!("Found {} content IDs: {:#?}", content_ids.len(), content_ids );
         targets.extend( content_ids );
 
-        let shortcuts = anchor.shortcuts()?;
+        let shortcuts = anchor.shortcuts( &cutoff )?;
         debug!("Found {} content update shortcuts: {:#?}", shortcuts.len(), shortcuts );
         for (_,base,target) in shortcuts {
             updates.insert( base, target );
         }
     }
 
+    // Approved updates take precedence over proposals from the same revision
+    if include_proposals {
+        for proposal in review::pending_proposals( &group_id, &group_rev, &cutoff )? {
+            updates.entry( proposal.content_prev ).or_insert( proposal.content_next );
+        }
+    }
+
     let mut content_evolutions = vec![];
 
     for addr in targets {
@@ -503,10 +794,21 @@ pub fn follow_all_group_content_evolutions_shortcuts(
 /// Get all group content using shortcuts with the optional 'content type' filter
 #[hdk_extern]
 pub fn get_all_group_content_targets_shortcuts(
-    (group_id, content_type, content_base): (ActionHash, Option<String>, Option<String>)
+    GetAllGroupContentShortcutsInput(group_id, content_type, content_base, as_of, include_proposals): GetAllGroupContentShortcutsInput
+) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
+    group_content_targets_shortcuts( group_id, content_type, content_base, as_of, include_proposals )
+}
+
+
+fn group_content_targets_shortcuts(
+    group_id: ActionHash,
+    content_type: Option<String>,
+    content_base: Option<String>,
+    as_of: Option<AsOf>,
+    include_proposals: bool,
 ) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
     Ok(
-        follow_all_group_content_evolutions_shortcuts( (group_id, content_type, content_base) )?.into_iter()
+        group_content_evolutions_shortcuts( group_id, content_type, content_base, as_of, include_proposals )?.into_iter()
             .filter_map( |(key, evolutions)| {
                 let latest_addr = evolutions.last()?.to_owned();
                 Some( (key, latest_addr) )
@@ -519,21 +821,60 @@ pub fn get_all_group_content_targets_shortcuts(
 /// Calculate the [`EntryHash`] for a [`ContributionsAnchorEntry`]
 #[hdk_extern]
 pub fn group_auth_anchor_hash(input: GroupAuthInput) -> ExternResult<EntryHash> {
-    Ok( hash_entry( ContributionsAnchorEntry( input.group_id, input.author ) )? )
+    hash_entry( ContributionsAnchorEntry( input.group_id, input.author ) )
 }
 
 
 /// Calculate the [`EntryHash`] for a [`ArchivedContributionsAnchorEntry`]
 #[hdk_extern]
 pub fn group_auth_archive_anchor_hash(input: GroupAuthInput) -> ExternResult<EntryHash> {
-    Ok( hash_entry( ArchivedContributionsAnchorEntry::new( input.group_id, input.author ) )? )
+    hash_entry( ArchivedContributionsAnchorEntry::new( input.group_id, input.author ) )
+}
+
+
+/// Find an existing link with the same base, target, and tag
+///
+/// The group revision suffix of link tags is ignored.
+fn find_matching_link(
+    base: &EntryHash,
+    link_type: LinkTypes,
+    target: &AnyLinkableHash,
+    tag: &[u8],
+) -> ExternResult<Option<ActionHash>> {
+    let links = get_links(
+        create_link_input(
+            base,
+            &link_type,
+            &Some(tag.to_vec()),
+        )?
+    )?;
+
+    Ok(
+        links.into_iter()
+            .find(|link| link.target == *target && tag_without_revision( &link.tag ) == tag )
+            .map(|link| link.create_link_hash )
+    )
 }
 
 
 /// Attach some new content to the given group
+///
+/// The content type must be declared by the latest group revision (if it declares any) and the
+/// link tag records that revision for validation.
 #[hdk_extern]
 pub fn create_content_link(input: CreateContributionLinkInput) -> ExternResult<ActionHash> {
     let author = agent_id()?;
+    let group_rev = latest_group_rev( &input.group_id )?;
+    let group : GroupEntry = must_get( &group_rev )?.try_into()?;
+
+    // Same check as validation so that a bad item does not fail a whole batch at commit time
+    if !group.allows_content_type( &input.content_type ) {
+        Err(CoopContentError::UndeclaredContentType {
+            content_type: input.content_type.to_owned(),
+            group_rev: group_rev.to_owned(),
+        })?
+    }
+
     let tag = format!("#{}#:{}", input.content_type, input.content_base.unwrap_or("".to_string()) );
     debug!("Creating content link from ContributionsAnchorEntry( {}, {} ) =[{}]=> {}", input.group_id, author, tag, input.content_target );
     let anchor = ContributionsAnchorEntry( input.group_id, author );
@@ -541,21 +882,52 @@ pub fn create_content_link(input: CreateContributionLinkInput) -> ExternResult<A
 
     create_if_not_exists( &anchor )?;
 
-    Ok(
-        create_link(
-            anchor_hash,
-            input.content_target,
-            LinkTypes::Contribution,
-            tag.as_str().as_bytes().to_vec()
-        )?
+    if let Some(existing) = find_matching_link( &anchor_hash, LinkTypes::Contribution, &input.content_target, tag.as_bytes() )? {
+        debug!("Content link already exists: {}", existing );
+        return Ok( existing );
+    }
+
+    create_link(
+        anchor_hash,
+        input.content_target,
+        LinkTypes::Contribution,
+        format!("{}{}{}", tag, CONTRIBUTION_TAG_REV_SEPARATOR, group_rev ).into_bytes()
     )
 }
 
 
 /// Register an update to some content for the given group
+///
+/// With `respect_locks`, the update is refused if another agent holds a lock on the content.  In
+/// groups that review updates, a member who is not a reviewer creates a proposal link instead.
 #[hdk_extern]
 pub fn create_content_update_link(input: CreateContributionUpdateLinkInput) -> ExternResult<ActionHash> {
     let author = agent_id()?;
+
+    if input.respect_locks {
+        locks::check_content_unlocked( &input.group_id, &input.content_id )?;
+    }
+
+    // Same check as validation so that a bad item does not fail a whole batch at commit time
+    if let (Some(id_addr), Some(prev_addr)) = (
+        input.content_id.clone().into_action_hash(),
+        input.content_prev.clone().into_action_hash(),
+    ) {
+        if id_addr != trace_origin_root( &prev_addr )?.0 {
+            Err(CoopContentError::NotARevision {
+                id: id_addr,
+                address: prev_addr,
+            })?
+        }
+    }
+
+    let group_rev = latest_group_rev( &input.group_id )?;
+    let group : GroupEntry = must_get( &group_rev )?.try_into()?;
+
+    if group.requires_review_for( &author ) {
+        return review::create_content_proposal_link( input, group_rev );
+    }
+
     let tag = format!("{}:{}", input.content_id, input.content_prev );
     let anchor = ContributionsAnchorEntry( input.group_id, author );
     let anchor_hash = hash_entry( &anchor )?;
@@ -563,14 +935,45 @@ pub fn create_content_update_link(input: CreateContributionUpdateLinkInput) -> E
 
     create_if_not_exists( &anchor )?;
 
+    if let Some(existing) = find_matching_link( &anchor_hash, LinkTypes::ContributionUpdate, &input.content_next, tag.as_bytes() )? {
+        debug!("Content update link already exists: {}", existing );
+        return Ok( existing );
+    }
+
     debug!("Creating content update link from {} --'{}'--> {}", anchor_hash, tag, input.content_next );
+    create_link(
+        anchor_hash,
+        input.content_next,
+        LinkTypes::ContributionUpdate,
+        format!("{}{}{}", tag, CONTRIBUTION_TAG_REV_SEPARATOR, group_rev ).into_bytes()
+    )
+}
+
+
+/// Attach many new contents to their groups
+///
+/// Returns a result for each input in the same order.
+#[hdk_extern]
+pub fn create_content_links(inputs: Vec<CreateContributionLinkInput>) -> ExternResult<Vec<BatchResult<ActionHash>>> {
+    debug!("Creating {} content links", inputs.len() );
     Ok(
-        create_link(
-            anchor_hash,
-            input.content_next,
-            LinkTypes::ContributionUpdate,
-            tag.into_bytes()
-        )?
+        inputs.into_iter()
+            .map( |input| create_content_link( input ).into() )
+            .collect()
+    )
+}
+
+
+/// Register many content updates to their groups
+///
+/// Returns a result for each input in the same order.
+#[hdk_extern]
+pub fn create_content_update_links(inputs: Vec<CreateContributionUpdateLinkInput>) -> ExternResult<Vec<BatchResult<ActionHash>>> {
+    debug!("Creating {} content update links", inputs.len() );
+    Ok(
+        inputs.into_iter()
+            .map( |input| create_content_update_link( input ).into() )
+            .collect()
     )
 }
 
@@ -637,32 +1040,20 @@ pub fn get_group_content_evolutions_full_trace(
 ) -> ExternResult<Vec<AnyLinkableHash>> {
     debug!("Get group ({}) content evolutions (full-trace): {}", input.group_id, input.content_id );
     let base_addr = resolve_action_addr( &input.content_id )?;
-    let latest_addr = follow_evolutions( &input.group_id )?.last().unwrap().to_owned();
-    let record = must_get( &latest_addr )?;
+    let (record, cutoff) = resolve_group_state( &input.group_id, &input.as_of )?;
     let group_rev = record.action_address().to_owned();
     let group : GroupEntry = record.try_into()?;
 
-    let mut archived_updates : Vec<ActionHash> = vec![];
-    let auth_archive_anchors = GroupEntry::group_auth_archive_anchor_hashes( &group_rev )?;
-
-    debug!("Found {} auth archives for group rev '{}'", auth_archive_anchors.len(), group_rev );
-    for auth_archive_addr in auth_archive_anchors.iter() {
-        let anchor : ArchivedContributionsAnchorEntry = must_get( auth_archive_addr )?.try_into()?;
-
-        let archive_updates = anchor.update_targets()?;
-        let update_actions : Vec<ActionHash> = archive_updates.iter()
-            .cloned()
-            .filter_map(|target| target.into_action_hash() )
-            .collect();
-        debug!("Removed {}/{} archive updates because they were not ActionHash targets", archive_updates.len() - update_actions.len(), archive_updates.len() );
-        archived_updates.extend( update_actions );
-    }
+    let mut archived_updates = group_archived_updates( &group_rev )?;
+    let (authors, reviewed_updates) = review::content_authorities( &group, &group_rev, &cutoff, input.include_proposals )?;
+    archived_updates.extend( reviewed_updates );
 
     Ok(
-        follow_evolutions_using_authorities_with_exceptions(
+        follow_group_content_evolutions(
             &base_addr,
-            &group.contributors(),
-            &archived_updates
+            &authors,
+            &archived_updates,
+            &cutoff,
         )?.into_iter().map( |hash| hash.into() ).collect()
     )
 }
@@ -674,12 +1065,15 @@ pub fn get_group_content_evolutions_shortcuts(
     input: GetGroupContentInput
 ) -> ExternResult<Vec<AnyLinkableHash>> {
     debug!("Get group ({}) content evolutions (shortcuts): {}", input.group_id, input.content_id );
-    let all_content_evolutions : EvolutionMap = follow_all_group_content_evolutions_shortcuts( (input.group_id, None, None) )?
+    let all_content_evolutions : EvolutionMap = group_content_evolutions_shortcuts( input.group_id.to_owned(), None, None, input.as_of, input.include_proposals )?
         .into_iter().collect();
 
     debug!("Looking for {} in: {:#?}", input.content_id, all_content_evolutions );
-    let evolutions = all_content_evolutions.get( &input.content_id.clone().into() )
-        .ok_or(guest_error!(format!("Content ID ({}) is not in group content: {:?}", input.content_id, all_content_evolutions.keys() )))?
+    let evolutions = all_content_evolutions.get( &input.content_id.clone() )
+        .ok_or(CoopContentError::ContentNotFound {
+            group_id: input.group_id.to_owned(),
+            content_id: input.content_id.to_owned(),
+        })?
         .to_owned();
 
     Ok( evolutions )
@@ -706,10 +1100,11 @@ pub fn get_group_content_latest_full_trace(
     input: GetGroupContentInput
 ) -> ExternResult<AnyLinkableHash> {
     debug!("Get group ({}) content latest (full-trace): {}", input.group_id, input.content_id );
-    Ok(
-        get_group_content_evolutions_full_trace( input )?
-            .last().unwrap().to_owned()
-    )
+    let (group_id, content_id) = (input.group_id.to_owned(), input.content_id.to_owned());
+
+    get_group_content_evolutions_full_trace( input )?
+        .last().cloned()
+        .ok_or( CoopContentError::ContentNotFound { group_id, content_id }.into() )
 }
 
 
@@ -719,13 +1114,345 @@ pub fn get_group_content_latest_shortcuts(
     input: GetGroupContentInput
 ) -> ExternResult<AnyLinkableHash> {
     debug!("Get group ({}) content latest (shortcuts): {}", input.group_id, input.content_id );
-    Ok(
-        get_group_content_evolutions_shortcuts( input )?
-            .last().unwrap().to_owned()
-    )
+    let (group_id, content_id) = (input.group_id.to_owned(), input.content_id.to_owned());
+
+    get_group_content_evolutions_shortcuts( input )?
+        .last().cloned()
+        .ok_or( CoopContentError::ContentNotFound { group_id, content_id }.into() )
+}
+
+
+
+/// Get all contribution (create and update) links based on the given anchor
+fn anchor_contribution_links(anchor_hash: &EntryHash) -> ExternResult<Vec<Link>> {
+    Ok( dedupe_links( get_links(
+        create_link_input(
+            anchor_hash,
+            &vec![
+                LinkTypes::Contribution,
+                LinkTypes::ContributionUpdate,
+            ],
+            &None::<()>,
+        )?
+    )? ) )
 }
 
 
+/// Get a time-ordered feed of the content and membership events in a group
+#[hdk_extern]
+pub fn get_group_activity(input: GetGroupActivityInput) -> ExternResult<Vec<GroupActivity>> {
+    debug!("Get group activity: {:?}", input );
+    let group_revs = follow_evolutions( &input.group_id )?;
+    let mut events = vec![];
+
+    // Membership events
+    let mut prev_group : Option<GroupEntry> = None;
+
+    for group_rev in group_revs.iter() {
+        let record = must_get( group_rev )?;
+        let timestamp = record.action().timestamp();
+        let author = record.action().author().to_owned();
+        let group : GroupEntry = record.try_into()?;
+
+        let (added, removed) = match &prev_group {
+            Some(prev) => {
+                let diff = prev.contributors_diff( &group );
+                ( diff.added, diff.removed )
+            },
+            None => ( group.contributors(), vec![] ),
+        };
+
+        for agent in added {
+            events.push( GroupActivity::MemberAdded {
+                timestamp,
+                author: author.to_owned(),
+                group_rev: group_rev.to_owned(),
+                agent,
+            });
+        }
+
+        for agent in removed {
+            events.push( GroupActivity::MemberRemoved {
+                timestamp,
+                author: author.to_owned(),
+                group_rev: group_rev.to_owned(),
+                agent,
+            });
+        }
+
+        prev_group = Some(group);
+    }
+
+    // Contribution events
+    let latest_rev = group_revs.last().unwrap_or( &input.group_id );
+    let mut contributor_links : Vec<(AgentPubKey, Vec<Link>)> = vec![];
+
+    for anchor_addr in GroupEntry::group_auth_anchor_hashes( latest_rev )? {
+        let anchor : ContributionsAnchorEntry = must_get( &anchor_addr )?.try_into()?;
+        contributor_links.push((
+            anchor.author().to_owned(),
+            anchor_contribution_links( &anchor_addr )?,
+        ));
+    }
+
+    for archive_addr in GroupEntry::group_auth_archive_anchor_hashes( latest_rev )? {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( &archive_addr )?.try_into()?;
+        let archived_links = anchor_contribution_links( &archive_addr )?;
+
+        // Archived links are copies so the originals are preferred for their real timestamps
+        let original_anchor = ContributionsAnchorEntry( input.group_id.to_owned(), anchor.author().to_owned() );
+        let mut links : Vec<Link> = anchor_contribution_links( &hash_entry( &original_anchor )? )?
+            .into_iter()
+            .filter(|link| archived_links.iter().any(|archived| {
+                archived.target == link.target && archived.tag == link.tag
+            }))
+            .collect();
+        links.extend( archived_links );
+
+        contributor_links.push(( anchor.author().to_owned(), links ));
+    }
+
+    // Deduplicate by link type and target, keeping the earliest occurrence
+    let mut content_links : HashMap<(LinkType, AnyLinkableHash), (AgentPubKey, Link)> = HashMap::new();
+
+    for (author, links) in contributor_links {
+        for link in links {
+            let key = ( link.link_type, link.target.to_owned() );
+
+            if let Some((_, existing)) = content_links.get( &key ) {
+                if existing.timestamp <= link.timestamp {
+                    continue;
+                }
+            }
+
+            content_links.insert( key, ( author.to_owned(), link ) );
+        }
+    }
+
+    let mut content_types : HashMap<AnyLinkableHash, String> = HashMap::new();
+
+    for (author, link) in content_links.values() {
+        match LinkTypes::from_type( link.zome_index, link.link_type )? {
+            Some(LinkTypes::Contribution) => {
+                let (content_type, content_base) = match parse_contribution_tag( &link.tag ) {
+                    Some(parts) => parts,
+                    None => continue,
+                };
+
+                content_types.insert( link.target.to_owned(), content_type.to_owned() );
+                events.push( GroupActivity::ContentCreated {
+                    timestamp: link.timestamp,
+                    author: author.to_owned(),
+                    content_id: link.target.to_owned(),
+                    content_type,
+                    content_base,
+                });
+            },
+            Some(LinkTypes::ContributionUpdate) => (),
+            _ => continue,
+        }
+    }
+
+    for (author, link) in content_links.values() {
+        if let Some(LinkTypes::ContributionUpdate) = LinkTypes::from_type( link.zome_index, link.link_type )? {
+            let (content_id, content_prev) = match parse_contribution_update_tag( &link.tag ) {
+                Some(parts) => parts,
+                None => continue,
+            };
+
+            events.push( GroupActivity::ContentUpdated {
+                timestamp: link.timestamp,
+                author: author.to_owned(),
+                content_type: content_types.get( &content_id ).cloned(),
+                content_id,
+                content_prev,
+                content_next: link.target.to_owned(),
+            });
+        }
+    }
+
+    let mut events : Vec<GroupActivity> = events.into_iter()
+        .filter(|event| match &input.content_type {
+            Some(content_type) => event.content_type() == Some(content_type),
+            None => true,
+        })
+        .filter(|event| match &input.author {
+            Some(author) => event.author() == author,
+            None => true,
+        })
+        .filter(|event| match &input.since {
+            Some(since) => event.timestamp() > since,
+            None => true,
+        })
+        .collect();
+
+    events.sort_by_key(|event| event.timestamp().to_owned() );
+
+    Ok( events )
+}
+
+
+/// Get the IDs of the groups that an agent has linked to
+fn agent_group_ids(agent: &AgentPubKey) -> ExternResult<Vec<ActionHash>> {
+    let mut group_ids = vec![];
+
+    for link in get_links( create_link_input( agent, &LinkTypes::Group, &None::<()> )? )? {
+        if let Some(group_id) = link.target.into_action_hash() {
+            if !group_ids.contains( &group_id ) {
+                group_ids.push( group_id );
+            }
+        }
+    }
+
+    Ok( group_ids )
+}
+
+
+/// Get an agent's contributions anchors that are recognized by the latest state of a group
+///
+/// The active anchor is only included if the agent is a current contributor.
+fn agent_group_anchors(
+    group_id: &ActionHash,
+    agent: &AgentPubKey,
+) -> ExternResult<Vec<(EntryHash, ContributionAnchors)>> {
+    let latest_addr = latest_group_rev( group_id )?;
+    let group : GroupEntry = must_get( &latest_addr )?.try_into()?;
+    let mut anchors = vec![];
+
+    if group.is_contributor( agent ) {
+        let anchor = ContributionsAnchorEntry( group_id.to_owned(), agent.to_owned() );
+        anchors.push(( hash_entry( &anchor )?, ContributionAnchors::Active(anchor) ));
+    }
+
+    for archive_addr in GroupEntry::group_auth_archive_anchor_hashes( &latest_addr )? {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( &archive_addr )?.try_into()?;
+
+        if anchor.author() == agent {
+            anchors.push(( archive_addr, ContributionAnchors::Archive(anchor) ));
+        }
+    }
+
+    Ok( anchors )
+}
+
+
+/// Map the content IDs of a group's latest state to their content type
+fn group_content_types(group_id: &ActionHash) -> ExternResult<HashMap<AnyLinkableHash, String>> {
+    let latest_addr = latest_group_rev( group_id )?;
+    let mut anchor_hashes = GroupEntry::group_auth_anchor_hashes( &latest_addr )?;
+    anchor_hashes.extend( GroupEntry::group_auth_archive_anchor_hashes( &latest_addr )? );
+
+    let mut content_types = HashMap::new();
+
+    for anchor_hash in anchor_hashes {
+        for link in get_links( create_link_input( &anchor_hash, &LinkTypes::Contribution, &None::<()> )? )? {
+            if let Some((content_type, _)) = parse_contribution_tag( &link.tag ) {
+                content_types.insert( link.target, content_type );
+            }
+        }
+    }
+
+    Ok( content_types )
+}
+
+
+/// Get the active and archived contributions anchors of an agent
+///
+/// Uses the given group or all groups that the agent has linked to.
+#[hdk_extern]
+pub fn get_agent_contribution_anchors(
+    input: GetAgentContributionsInput
+) -> ExternResult<Vec<(EntryHash, ContributionAnchors)>> {
+    let group_ids = match input.group_id {
+        Some(group_id) => vec![ group_id ],
+        None => agent_group_ids( &input.agent )?,
+    };
+    let mut anchors = vec![];
+
+    for group_id in group_ids {
+        anchors.extend( agent_group_anchors( &group_id, &input.agent )? );
+    }
+
+    Ok( anchors )
+}
+
+
+/// Get the contribution and update targets of an agent grouped by group and content type
+///
+/// Uses the given group or all groups that the agent has linked to.
+#[hdk_extern]
+pub fn get_agent_contributions(
+    input: GetAgentContributionsInput
+) -> ExternResult<Vec<AgentContributions>> {
+    debug!("Get agent contributions: {:?}", input );
+    let group_ids = match input.group_id {
+        Some(group_id) => vec![ group_id ],
+        None => agent_group_ids( &input.agent )?,
+    };
+    let mut results = vec![];
+
+    for group_id in group_ids {
+        let mut create_links = vec![];
+        let mut update_links = vec![];
+
+        for (anchor_hash, _) in agent_group_anchors( &group_id, &input.agent )? {
+            create_links.extend( get_links( create_link_input( &anchor_hash, &LinkTypes::Contribution, &None::<()> )? )? );
+            update_links.extend( get_links( create_link_input( &anchor_hash, &LinkTypes::ContributionUpdate, &None::<()> )? )? );
+        }
+
+        let mut content_types : BTreeMap<String, ContributionTargets> = BTreeMap::new();
+        let mut known_types : HashMap<AnyLinkableHash, String> = HashMap::new();
+
+        for link in dedupe_links( create_links ) {
+            let content_type = match parse_contribution_tag( &link.tag ) {
+                Some((content_type, _)) => content_type,
+                None => continue,
+            };
+            let targets = content_types.entry( content_type.to_owned() ).or_default();
+
+            if !targets.creates.contains( &link.target ) {
+                targets.creates.push( link.target.to_owned() );
+            }
+            known_types.insert( link.target, content_type );
+        }
+
+        // Only collect the group's content types when an update is for content made by others
+        let mut group_types : Option<HashMap<AnyLinkableHash, String>> = None;
+
+        for link in dedupe_links( update_links ) {
+            let content_id = match parse_contribution_update_tag( &link.tag ) {
+                Some((content_id, _)) => content_id,
+                None => continue,
+            };
+            let content_type = match known_types.get( &content_id ) {
+                Some(content_type) => content_type.to_owned(),
+                None => {
+                    if group_types.is_none() {
+                        group_types = Some( group_content_types( &group_id )? );
+                    }
+
+                    group_types.as_ref()
+                        .and_then(|types| types.get( &content_id ) )
+                        .cloned()
+                        .unwrap_or_default()
+                },
+            };
+            let targets = content_types.entry( content_type ).or_default();
+
+            if !targets.updates.contains( &link.target ) {
+                targets.updates.push( link.target );
+            }
+        }
+
+        results.push( AgentContributions {
+            group_id,
+            content_types,
+        });
+    }
+
+    Ok( results )
+}
+
 
 //
 // Generic
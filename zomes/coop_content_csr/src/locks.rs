@@ -0,0 +1,232 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    check_group_id,
+    latest_group_rev,
+    get_group_content_latest,
+};
+
+use std::time::Duration;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use hdi_extensions::{
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContentLockTag,
+    CoopContentError,
+    CONTENT_LOCK_DEFAULT_DURATION,
+    CONTENT_LOCK_MAX_DURATION,
+};
+use coop_content_sdk::{
+    create_link_input,
+    ContentLockInput,
+    LockContentInput,
+    GetGroupContentInput,
+    ContentLock,
+};
+
+
+/// Get the lock links on a content ID that target the given group (including expired locks)
+fn content_lock_links(group_id: &ActionHash, content_id: &AnyLinkableHash) -> ExternResult<Vec<(Link, ContentLockTag)>> {
+    let links = get_links(
+        create_link_input(
+            content_id,
+            &LinkTypes::ContentLock,
+            &None::<()>,
+        )?
+    )?;
+
+    Ok(
+        links.into_iter()
+            .filter( |link| link.target == group_id.clone().into() )
+            .filter_map( |link| {
+                let lock_tag = ContentLockTag::try_from( link.tag.to_owned() ).ok()?;
+                Some( (link, lock_tag) )
+            })
+            .collect()
+    )
+}
+
+/// Get the effective lock on a content ID within a group
+///
+/// When concurrent locks are unexpired, the earliest one wins so that every agent resolves the
+/// same holder.
+fn active_content_lock(group_id: &ActionHash, content_id: &AnyLinkableHash) -> ExternResult<Option<ContentLock>> {
+    let now = sys_time()?;
+
+    Ok(
+        content_lock_links( group_id, content_id )?.into_iter()
+            .filter( |(_, lock_tag)| lock_tag.expires_at > now )
+            .min_by_key( |(link, _)| (link.timestamp, link.create_link_hash.to_owned()) )
+            .map( |(link, lock_tag)| ContentLock {
+                id: link.create_link_hash,
+                group_id: group_id.to_owned(),
+                content_id: content_id.to_owned(),
+                holder: link.author,
+                group_rev: lock_tag.group_rev,
+                created_at: link.timestamp,
+                expires_at: lock_tag.expires_at,
+            })
+    )
+}
+
+/// Check that no other agent holds a lock on the content
+pub fn check_content_unlocked(group_id: &ActionHash, content_id: &AnyLinkableHash) -> ExternResult<()> {
+    if let Some(lock) = active_content_lock( group_id, content_id )? {
+        if lock.holder != agent_id()? {
+            Err(CoopContentError::ContentLocked {
+                group_id: lock.group_id,
+                content_id: lock.content_id,
+                holder: lock.holder,
+                expires_at: lock.expires_at,
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Take a time-limited lock on a content ID within a group
+///
+/// Renews the lock if the current agent already holds it.
+#[hdk_extern]
+pub fn lock_content(input: LockContentInput) -> ExternResult<ContentLock> {
+    check_group_id( &input.group_id )?;
+
+    let duration = input.duration.unwrap_or( CONTENT_LOCK_DEFAULT_DURATION );
+
+    if duration == 0 || duration > CONTENT_LOCK_MAX_DURATION {
+        Err(guest_error!(format!(
+            "Content lock duration must be 1-{} seconds; not {}",
+            CONTENT_LOCK_MAX_DURATION, duration,
+        )))?
+    }
+
+    // Verify that the content belongs to the group
+    get_group_content_latest( GetGroupContentInput {
+        group_id: input.group_id.to_owned(),
+        content_id: input.content_id.to_owned(),
+        full_trace: None,
+        as_of: None,
+        include_proposals: false,
+    })?;
+
+    let agent_id = agent_id()?;
+    let group_rev = latest_group_rev( &input.group_id )?;
+    let group : GroupEntry = must_get( &group_rev )?.try_into()?;
+
+    if !group.is_contributor( &agent_id ) {
+        Err(CoopContentError::NotAContributor {
+            group_id: input.group_id.to_owned(),
+            agent: agent_id.to_owned(),
+        })?
+    }
+
+    check_content_unlocked( &input.group_id, &input.content_id )?;
+
+    let previous : Vec<ActionHash> = content_lock_links( &input.group_id, &input.content_id )?.into_iter()
+        .filter( |(link, _)| link.author == agent_id )
+        .map( |(link, _)| link.create_link_hash )
+        .collect();
+    let now = sys_time()?;
+    let lock_tag = ContentLockTag {
+        group_rev: group_rev.to_owned(),
+        expires_at: ( now + Duration::from_secs( duration ) )
+            .map_err( |err| guest_error!(format!("Invalid content lock expiry: {}", err )) )?,
+    };
+
+    debug!("Locking content {} in group {} until {}", input.content_id, input.group_id, lock_tag.expires_at );
+    let id = create_link(
+        input.content_id.to_owned(),
+        input.group_id.to_owned(),
+        LinkTypes::ContentLock,
+        lock_tag.to_link_tag()?,
+    )?;
+
+    for addr in previous {
+        delete_link( addr )?;
+    }
+
+    Ok( ContentLock {
+        id,
+        group_id: input.group_id,
+        content_id: input.content_id,
+        holder: agent_id,
+        group_rev,
+        created_at: now,
+        expires_at: lock_tag.expires_at,
+    })
+}
+
+
+/// Get the effective lock on a content ID within a group
+#[hdk_extern]
+pub fn get_content_lock(input: ContentLockInput) -> ExternResult<Option<ContentLock>> {
+    active_content_lock( &input.group_id, &input.content_id )
+}
+
+
+/// Release the current agent's locks on a content ID within a group
+#[hdk_extern]
+pub fn unlock_content(input: ContentLockInput) -> ExternResult<Vec<ActionHash>> {
+    let agent_id = agent_id()?;
+    let mut deleted = vec![];
+
+    for (link, _) in content_lock_links( &input.group_id, &input.content_id )? {
+        if link.author == agent_id {
+            deleted.push( delete_link( link.create_link_hash )? );
+        }
+    }
+    debug!("Released {} locks on content {} in group {}", deleted.len(), input.content_id, input.group_id );
+
+    Ok( deleted )
+}
+
+
+/// Break every lock on a content ID within a group
+///
+/// Only admins can break locks.  Each lock is checked against the group revision it was taken
+/// under, so an admin added after a lock was taken cannot break it (it still expires).
+#[hdk_extern]
+pub fn break_content_lock(input: ContentLockInput) -> ExternResult<Vec<ActionHash>> {
+    let agent_id = agent_id()?;
+    let group : GroupEntry = must_get( &latest_group_rev( &input.group_id )? )?.try_into()?;
+
+    if !group.is_admin( &agent_id ) {
+        Err(CoopContentError::NotAnAdmin {
+            group_id: input.group_id.to_owned(),
+            agent: agent_id.to_owned(),
+            operation: "break content locks".to_string(),
+        })?
+    }
+
+    let mut deleted = vec![];
+
+    for (link, lock_tag) in content_lock_links( &input.group_id, &input.content_id )? {
+        if link.author != agent_id {
+            let lock_group : GroupEntry = must_get( &lock_tag.group_rev )?.try_into()?;
+
+            if !lock_group.is_admin( &agent_id ) {
+                Err(CoopContentError::NotAnAdmin {
+                    group_id: input.group_id.to_owned(),
+                    agent: agent_id.to_owned(),
+                    operation: format!("break locks taken under group revision '{}'", lock_tag.group_rev ),
+                })?
+            }
+        }
+
+        deleted.push( delete_link( link.create_link_hash )? );
+    }
+    debug!("Broke {} locks on content {} in group {}", deleted.len(), input.content_id, input.group_id );
+
+    Ok( deleted )
+}
@@ -0,0 +1,29 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::must_get;
+use hdi_extensions::trace_origin;
+use coop_content_sdk::GroupMembershipProof;
+
+
+/// Get the group revision records from the group ID to the given revision
+///
+/// The proof can be stored with content in another DNA so that its validation does not need to get
+/// the group records.
+#[hdk_extern]
+pub fn get_group_membership_proof(group_rev: ActionHash) -> ExternResult<GroupMembershipProof> {
+    debug!("Get group membership proof: {}", group_rev );
+    let mut revisions = vec![];
+
+    for (addr, _) in trace_origin( &group_rev )?.into_iter().rev() {
+        revisions.push( must_get( &addr )? );
+    }
+
+    Ok(GroupMembershipProof {
+        revisions,
+    })
+}
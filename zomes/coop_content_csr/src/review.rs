@@ -0,0 +1,282 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    GroupLinks,
+    ContributionsLinks,
+    ArchivedContributionsLinks,
+    check_group_id,
+    latest_group_rev,
+    create_if_not_exists,
+    find_matching_link,
+};
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use hdi_extensions::{
+    trace_origin_root,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContributionsAnchorEntry,
+    ArchivedContributionsAnchorEntry,
+    ContributionProposalTag,
+    CoopContentError,
+    CONTRIBUTION_TAG_REV_SEPARATOR,
+};
+use coop_content_sdk::{
+    CreateContributionUpdateLinkInput,
+    GetContentProposalsInput,
+    ContentProposal,
+};
+
+
+/// Get the targets of the contribution update links in a group revision's anchors
+///
+/// Update links from the active anchors of members who need review are skipped because they are
+/// not approvals.
+fn group_update_targets(
+    group_rev: &ActionHash,
+    cutoff: &Option<Timestamp>,
+) -> ExternResult<Vec<AnyLinkableHash>> {
+    let group : GroupEntry = must_get( group_rev )?.try_into()?;
+    let mut targets = vec![];
+
+    for anchor_hash in GroupEntry::group_auth_archive_anchor_hashes( group_rev )? {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+        targets.extend( anchor.update_targets()? );
+    }
+
+    for anchor_hash in GroupEntry::group_auth_anchor_hashes( group_rev )? {
+        let anchor : ContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+
+        if group.requires_review_for( anchor.author() ) {
+            continue;
+        }
+
+        targets.extend( anchor.update_targets( cutoff )? );
+    }
+
+    Ok( targets )
+}
+
+/// Get the proposals made by a group revision's current contributors that have not been approved,
+/// earliest first
+///
+/// A proposal is approved once any contributor's update link has the same target.
+pub fn pending_proposals(
+    group_id: &ActionHash,
+    group_rev: &ActionHash,
+    cutoff: &Option<Timestamp>,
+) -> ExternResult<Vec<ContentProposal>> {
+    let approved = group_update_targets( group_rev, cutoff )?;
+    let mut proposals : Vec<ContentProposal> = vec![];
+
+    for anchor_hash in GroupEntry::group_auth_anchor_hashes( group_rev )? {
+        let anchor : ContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+
+        for link in anchor.proposal_links( cutoff )? {
+            let proposal_tag = match ContributionProposalTag::try_from( link.tag ) {
+                Ok(proposal_tag) => proposal_tag,
+                Err(reason) => {
+                    debug!("Skipping contribution proposal with malformed tag: {}", reason );
+                    continue;
+                },
+            };
+
+            if approved.contains( &link.target ) {
+                continue;
+            }
+
+            proposals.push( ContentProposal {
+                id: link.create_link_hash,
+                group_id: group_id.to_owned(),
+                content_id: proposal_tag.content_id,
+                content_prev: proposal_tag.content_prev,
+                content_next: link.target,
+                author: link.author,
+                group_rev: proposal_tag.group_rev,
+                timestamp: link.timestamp,
+            });
+        }
+    }
+
+    proposals.sort_by_key( |proposal| proposal.timestamp );
+
+    Ok( proposals )
+}
+
+/// Get the authors and extra update exceptions used to follow content evolutions (full trace)
+///
+/// In groups that review updates, only reviewers are authorities and the updates of other members
+/// are followed only if they have an update link (ie. approved).  Pending proposals are added as
+/// exceptions when requested.
+pub fn content_authorities(
+    group: &GroupEntry,
+    group_rev: &ActionHash,
+    cutoff: &Option<Timestamp>,
+    include_proposals: bool,
+) -> ExternResult<(Vec<AgentPubKey>, Vec<ActionHash>)> {
+    let mut authors = group.contributors();
+    let mut exceptions = vec![];
+
+    if group.requires_review() {
+        authors.retain( |agent| !group.requires_review_for( agent ) );
+        exceptions.extend(
+            group_update_targets( group_rev, cutoff )?.into_iter()
+                .filter_map( |target| target.into_action_hash() )
+        );
+    }
+
+    if include_proposals {
+        let group_id = trace_origin_root( group_rev )?.0;
+
+        exceptions.extend(
+            pending_proposals( &group_id, group_rev, cutoff )?.into_iter()
+                .filter_map( |proposal| proposal.content_next.into_action_hash() )
+        );
+    }
+
+    Ok( (authors, exceptions) )
+}
+
+/// Create a proposal link for a content update on the current agent's contributions anchor
+pub fn create_content_proposal_link(
+    input: CreateContributionUpdateLinkInput,
+    group_rev: ActionHash,
+) -> ExternResult<ActionHash> {
+    let proposal_tag = ContributionProposalTag {
+        content_id: input.content_id,
+        content_prev: input.content_prev,
+        group_rev,
+    };
+    let anchor = ContributionsAnchorEntry( input.group_id, agent_id()? );
+    let anchor_hash = hash_entry( &anchor )?;
+
+    create_if_not_exists( &anchor )?;
+
+    if let Some(existing) = find_matching_link( &anchor_hash, LinkTypes::ContributionProposal, &input.content_next, proposal_tag.update_tag().as_bytes() )? {
+        debug!("Content proposal link already exists: {}", existing );
+        return Ok( existing );
+    }
+
+    debug!("Creating content proposal link from {} --'{}'--> {}", anchor_hash, proposal_tag.update_tag(), input.content_next );
+    create_link(
+        anchor_hash,
+        input.content_next,
+        LinkTypes::ContributionProposal,
+        proposal_tag.to_link_tag(),
+    )
+}
+
+/// Get a proposal's create link action with its group ID and tag
+fn get_proposal(proposal_id: &ActionHash) -> ExternResult<(CreateLink, ActionHash, ContributionProposalTag)> {
+    let create = match must_get_action( proposal_id.to_owned() )?.action().to_owned() {
+        Action::CreateLink(action) => action,
+        _ => Err(CoopContentError::NotAProposal {
+            address: proposal_id.to_owned(),
+        })?,
+    };
+
+    if LinkTypes::from_type( create.zome_index, create.link_type )? != Some(LinkTypes::ContributionProposal) {
+        Err(CoopContentError::NotAProposal {
+            address: proposal_id.to_owned(),
+        })?
+    }
+
+    let anchor_hash = match create.base_address.clone().into_entry_hash() {
+        Some(hash) => hash,
+        None => Err(CoopContentError::NotAProposal {
+            address: proposal_id.to_owned(),
+        })?,
+    };
+    let anchor : ContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+    let proposal_tag = ContributionProposalTag::try_from( create.tag.to_owned() )
+        .map_err( |reason| CoopContentError::MalformedTag {
+            link_type: "ContributionProposal".to_string(),
+            reason,
+        })?;
+
+    Ok( (create, anchor.0, proposal_tag) )
+}
+
+/// Check that the current agent is a reviewer in the latest revision of a group
+///
+/// Returns the agent and the latest revision.
+fn check_reviewer(group_id: &ActionHash) -> ExternResult<(AgentPubKey, ActionHash)> {
+    let agent_id = agent_id()?;
+    let group_rev = latest_group_rev( group_id )?;
+    let group : GroupEntry = must_get( &group_rev )?.try_into()?;
+
+    if !group.is_reviewer( &agent_id ) {
+        Err(CoopContentError::NotAReviewer {
+            group_id: group_id.to_owned(),
+            agent: agent_id.to_owned(),
+        })?
+    }
+
+    Ok( (agent_id, group_rev) )
+}
+
+
+/// Get the pending content proposals of a group, earliest first
+#[hdk_extern]
+pub fn get_content_proposals(input: GetContentProposalsInput) -> ExternResult<Vec<ContentProposal>> {
+    check_group_id( &input.group_id )?;
+
+    let group_rev = latest_group_rev( &input.group_id )?;
+
+    Ok(
+        pending_proposals( &input.group_id, &group_rev, &None )?.into_iter()
+            .filter( |proposal| input.content_id.as_ref()
+                .is_none_or( |content_id| proposal.content_id == *content_id ) )
+            .collect()
+    )
+}
+
+
+/// Approve a content proposal by linking its update from the current agent's contributions anchor
+///
+/// Only admins and declared reviewers can approve proposals.
+#[hdk_extern]
+pub fn approve_content_proposal(proposal_id: ActionHash) -> ExternResult<ActionHash> {
+    let (create, group_id, proposal_tag) = get_proposal( &proposal_id )?;
+    let (agent_id, group_rev) = check_reviewer( &group_id )?;
+
+    let tag = proposal_tag.update_tag();
+    let anchor = ContributionsAnchorEntry( group_id, agent_id );
+    let anchor_hash = hash_entry( &anchor )?;
+
+    create_if_not_exists( &anchor )?;
+
+    if let Some(existing) = find_matching_link( &anchor_hash, LinkTypes::ContributionUpdate, &create.target_address, tag.as_bytes() )? {
+        debug!("Content proposal already approved: {}", existing );
+        return Ok( existing );
+    }
+
+    debug!("Approving content proposal {} from {} --'{}'--> {}", proposal_id, anchor_hash, tag, create.target_address );
+    create_link(
+        anchor_hash,
+        create.target_address,
+        LinkTypes::ContributionUpdate,
+        format!("{}{}{}", tag, CONTRIBUTION_TAG_REV_SEPARATOR, group_rev ).into_bytes(),
+    )
+}
+
+
+/// Reject a content proposal (or withdraw it when the current agent is the author)
+#[hdk_extern]
+pub fn reject_content_proposal(proposal_id: ActionHash) -> ExternResult<ActionHash> {
+    let (create, group_id, _) = get_proposal( &proposal_id )?;
+
+    if create.author != agent_id()? {
+        check_reviewer( &group_id )?;
+    }
+
+    debug!("Rejecting content proposal: {}", proposal_id );
+    delete_link( proposal_id )
+}
@@ -1,12 +1,16 @@
 pub mod entry_traits;
 
 use crate::hdk::prelude::*;
+use crate::hdk_extensions::must_get;
 use crate::hdi_extensions::{
+    trace_origin_root,
     guest_error,
     AnyLinkableHashTransformer,
 };
 use coop_content::{
     LinkTypes,
+    CONTRIBUTION_TAG_REV_SEPARATOR,
+    split_contribution_tag,
 };
 use coop_content_sdk::{
     create_link_input,
@@ -23,9 +27,125 @@ pub use entry_traits::{
 };
 
 
+/// Parse the `(content type, content base)` from a contribution link tag
+///
+/// Contribution link tags are formatted as `#<content type>#:<content base>` followed by a group
+/// revision suffix
+pub fn parse_contribution_tag(tag: &LinkTag) -> Option<(String, Option<String>)> {
+    let tag_str = String::from_utf8( tag.to_owned().into_inner() ).ok()?;
+    let (content_type, content_base) = split_contribution_tag( &tag_str ).0
+        .strip_prefix("#")?.split_once("#:")?;
+
+    Some((
+        content_type.to_string(),
+        match content_base {
+            "" => None,
+            base => Some(base.to_string()),
+        },
+    ))
+}
+
+
+/// Parse the `(content ID, previous revision)` from a contribution update link tag
+///
+/// Contribution update link tags are formatted as `<content ID>:<previous revision>` followed by a
+/// group revision suffix
+pub fn parse_contribution_update_tag(tag: &LinkTag) -> Option<(AnyLinkableHash, AnyLinkableHash)> {
+    let tag_str = String::from_utf8( tag.to_owned().into_inner() ).ok()?;
+    let (tag_id, tag_rev) = split_contribution_tag( &tag_str ).0.split_once(":")
+        .or_else(|| {
+            debug!("Contribution update link has malformed tag: {}", tag_str );
+            None
+        })?;
+
+    Some((
+        AnyLinkableHash::try_from_string( tag_id ).ok()?,
+        AnyLinkableHash::try_from_string( tag_rev ).ok()?,
+    ))
+}
+
+
+/// Get a link tag without its group revision suffix
+pub fn tag_without_revision(tag: &LinkTag) -> &[u8] {
+    let bytes : &[u8] = tag.as_ref();
+
+    match bytes.iter().position( |byte| *byte == CONTRIBUTION_TAG_REV_SEPARATOR as u8 ) {
+        Some(index) => &bytes[..index],
+        None => bytes,
+    }
+}
+
+
+/// Remove duplicate links (same target and tag), keeping the earliest of each
+///
+/// Duplicates can exist from retried calls made before link creation was idempotent.  The group
+/// revision suffix of tags is ignored.
+pub fn dedupe_links(links: Vec<Link>) -> Vec<Link> {
+    let mut earliest : Vec<Link> = vec![];
+
+    for link in links {
+        match earliest.iter_mut().find( |kept| {
+            kept.target == link.target && tag_without_revision( &kept.tag ) == tag_without_revision( &link.tag )
+        }) {
+            Some(kept) => {
+                if link.timestamp < kept.timestamp {
+                    *kept = link;
+                }
+            },
+            None => earliest.push( link ),
+        }
+    }
+
+    earliest
+}
+
+
+/// Remove any links that were created after the given cutoff
+pub fn links_created_before(links: Vec<Link>, cutoff: &Option<Timestamp>) -> Vec<Link> {
+    match cutoff {
+        Some(cutoff) => links.into_iter()
+            .filter(|link| link.timestamp <= *cutoff )
+            .collect(),
+        None => links,
+    }
+}
+
+
+
+/// Get the prior revision and leaving agent when a group revision is a member removing themselves
+///
+/// Members cannot create auth links, so the anchors of a self-removal revision are carried over
+/// from the prior revision.
+pub fn self_removal_parent(group_rev: &ActionHash) -> ExternResult<Option<(ActionHash, AgentPubKey)>> {
+    let record = must_get( group_rev )?;
+    let (prior_rev, author) = match record.action() {
+        Action::Update(update) => (update.original_action_address.to_owned(), update.author.to_owned()),
+        _ => return Ok(None),
+    };
+    let group : GroupEntry = record.try_into()?;
+    let prior_group : GroupEntry = must_get( &prior_rev )?.try_into()?;
+
+    if prior_group.is_admin( &author ) || !prior_group.is_self_removal( &group, &author ) {
+        return Ok(None);
+    }
+
+    Ok( Some( (prior_rev, author) ) )
+}
+
 
 impl GroupLinks for GroupEntry {
     fn group_auth_anchor_hashes(base: &ActionHash) -> ExternResult<Vec<EntryHash>> {
+        if let Some((prior_rev, agent)) = self_removal_parent( base )? {
+            let group_id = trace_origin_root( base )?.0;
+            let leaver_anchor = hash_entry( &ContributionsAnchorEntry( group_id, agent ) )?;
+
+            return Ok(
+                GroupEntry::group_auth_anchor_hashes( &prior_rev )?.into_iter()
+                    .filter( |anchor_hash| *anchor_hash != leaver_anchor )
+                    .collect()
+            );
+        }
+
         let links = get_links(
             create_link_input(
                 base,
@@ -55,16 +175,24 @@ impl GroupLinks for GroupEntry {
             )?
         )?;
 
-        Ok(
-            links.into_iter()
-                .filter_map(|link| {
-                    link.target.into_entry_hash().or_else(|| {
-                        debug!("WARNING: Should be unreachable because LinkTypes::GroupAuthArchive validation only allows EntryHash target");
-                        None
-                    })
+        let mut anchor_hashes : Vec<EntryHash> = links.into_iter()
+            .filter_map(|link| {
+                link.target.into_entry_hash().or_else(|| {
+                    debug!("WARNING: Should be unreachable because LinkTypes::GroupAuthArchive validation only allows EntryHash target");
+                    None
                 })
-                .collect()
-        )
+            })
+            .collect();
+
+        if let Some((prior_rev, _)) = self_removal_parent( base )? {
+            for anchor_hash in GroupEntry::group_auth_archive_anchor_hashes( &prior_rev )? {
+                if !anchor_hashes.contains( &anchor_hash ) {
+                    anchor_hashes.push( anchor_hash );
+                }
+            }
+        }
+
+        Ok( anchor_hashes )
     }
 }
 
@@ -76,7 +204,8 @@ impl ContributionsLinks for ContributionsAnchorEntry {
     fn create_targets(
         &self,
         content_type: Option<String>,
-        content_base: Option<String>
+        content_base: Option<String>,
+        cutoff: &Option<Timestamp>,
     ) -> ExternResult<Vec<AnyLinkableHash>> {
         if content_type.is_none() && content_base.is_some() {
             Err(guest_error!(format!(
@@ -93,14 +222,16 @@ impl ContributionsLinks for ContributionsAnchorEntry {
         };
         debug!("Get links {}<{:?}> =[{}]=> *", base, LinkTypes::Contribution, tag );
 
-        Ok(
-            get_links(
-                create_link_input(
-                    &base,
-                    &LinkTypes::Contribution,
-                    &Some(tag.as_str().as_bytes().to_vec()),
-                )?
+        let links = get_links(
+            create_link_input(
+                &base,
+                &LinkTypes::Contribution,
+                &Some(tag.as_bytes().to_vec()),
             )?
+        )?;
+
+        Ok(
+            links_created_before( dedupe_links( links ), cutoff )
                 .into_iter()
                 .map(|link| link.target )
                 .collect()
@@ -108,44 +239,47 @@ impl ContributionsLinks for ContributionsAnchorEntry {
     }
 
     fn update_links(&self) -> ExternResult<Vec<Link>> {
-        get_links(
+        Ok( dedupe_links( get_links(
             create_link_input(
                 &self.base_hash()?,
                 &LinkTypes::ContributionUpdate,
                 &None::<()>,
             )?
-        )
+        )? ) )
     }
 
-    fn update_targets(&self) -> ExternResult<Vec<AnyLinkableHash>> {
+    fn update_targets(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<AnyLinkableHash>> {
         Ok(
-            self.update_links()?
+            links_created_before( self.update_links()?, cutoff )
                 .into_iter()
                 .map(|link| link.target )
                 .collect()
         )
     }
 
-    fn shortcuts(&self) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)>> {
-        Ok(self.update_links()?.into_iter()
+    fn shortcuts(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)>> {
+        Ok(links_created_before( self.update_links()?, cutoff ).into_iter()
             .filter_map(|link| {
-                let tag_str = String::from_utf8( link.tag.into_inner() ).ok()?;
-                let (tag_id, tag_rev) = tag_str.split_once(":")
-                    .or_else(|| {
-                        debug!("Contribution update link has malformed tag: {}", tag_str );
-                        None
-                    })?;
+                let (content_id, content_prev) = parse_contribution_update_tag( &link.tag )?;
 
-                Some((
-                    AnyLinkableHash::try_from_string( tag_id ).ok()?,
-                    AnyLinkableHash::try_from_string( tag_rev ).ok()?,
-                    link.target
-                ))
+                Some(( content_id, content_prev, link.target ))
             })
             .collect())
     }
+
+    fn proposal_links(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<Link>> {
+        Ok( links_created_before( dedupe_links( get_links(
+            create_link_input(
+                &self.base_hash()?,
+                &LinkTypes::ContributionProposal,
+                &None::<()>,
+            )?
+        )? ), cutoff ) )
+    }
 }
 
+// Archive anchors are not filtered by an as-of cutoff because their links are copies made after
+// the removal of links that were created before it.
 impl ArchivedContributionsLinks for ArchivedContributionsAnchorEntry {
     fn base_hash(&self) -> ExternResult<EntryHash> {
         hash_entry( self )
@@ -154,7 +288,7 @@ impl ArchivedContributionsLinks for ArchivedContributionsAnchorEntry {
     fn create_targets(
         &self,
         content_type: Option<String>,
-        content_base: Option<String>
+        content_base: Option<String>,
     ) -> ExternResult<Vec<AnyLinkableHash>> {
         if content_type.is_none() && content_base.is_some() {
             Err(guest_error!(format!(
@@ -171,14 +305,16 @@ impl ArchivedContributionsLinks for ArchivedContributionsAnchorEntry {
         };
         debug!("Get links {}<{:?}> =[{}]=> *", base, LinkTypes::Contribution, tag );
 
-        Ok(
-            get_links(
-                create_link_input(
-                    &base,
-                    &LinkTypes::Contribution,
-                    &Some(tag.as_str().as_bytes().to_vec()),
-                )?
+        let links = get_links(
+            create_link_input(
+                &base,
+                &LinkTypes::Contribution,
+                &Some(tag.as_bytes().to_vec()),
             )?
+        )?;
+
+        Ok(
+            dedupe_links( links )
                 .into_iter()
                 .map(|link| link.target )
                 .collect()
@@ -186,13 +322,13 @@ impl ArchivedContributionsLinks for ArchivedContributionsAnchorEntry {
     }
 
     fn update_links(&self) -> ExternResult<Vec<Link>> {
-        get_links(
+        Ok( dedupe_links( get_links(
             create_link_input(
                 &self.base_hash()?,
                 &LinkTypes::ContributionUpdate,
                 &None::<()>,
             )?
-        )
+        )? ) )
     }
 
     fn update_targets(&self) -> ExternResult<Vec<AnyLinkableHash>> {
@@ -207,18 +343,9 @@ impl ArchivedContributionsLinks for ArchivedContributionsAnchorEntry {
     fn shortcuts(&self) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)>> {
         Ok(self.update_links()?.into_iter()
             .filter_map(|link| {
-                let tag_str = String::from_utf8( link.tag.into_inner() ).ok()?;
-                let (tag_id, tag_rev) = tag_str.split_once(":")
-                    .or_else(|| {
-                        debug!("Contribution update link has malformed tag: {}", tag_str );
-                        None
-                    })?;
+                let (content_id, content_prev) = parse_contribution_update_tag( &link.tag )?;
 
-                Some((
-                    AnyLinkableHash::try_from_string( tag_id ).ok()?,
-                    AnyLinkableHash::try_from_string( tag_rev ).ok()?,
-                    link.target
-                ))
+                Some(( content_id, content_prev, link.target ))
             })
            .collect())
     }
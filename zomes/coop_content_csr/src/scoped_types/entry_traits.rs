@@ -7,10 +7,11 @@ pub trait GroupLinks {
 
 pub trait ContributionsLinks {
     fn base_hash(&self) -> ExternResult<EntryHash>;
-    fn create_targets(&self, content_type: Option<String>, content_base: Option<String>) -> ExternResult<Vec<AnyLinkableHash>>;
+    fn create_targets(&self, content_type: Option<String>, content_base: Option<String>, cutoff: &Option<Timestamp>) -> ExternResult<Vec<AnyLinkableHash>>;
     fn update_links(&self) -> ExternResult<Vec<Link>>;
-    fn update_targets(&self) -> ExternResult<Vec<AnyLinkableHash>>;
-    fn shortcuts(&self) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)>>;
+    fn update_targets(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<AnyLinkableHash>>;
+    fn shortcuts(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash, AnyLinkableHash)>>;
+    fn proposal_links(&self, cutoff: &Option<Timestamp>) -> ExternResult<Vec<Link>>;
 }
 
 
@@ -0,0 +1,261 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hdi_extensions,
+    latest_group_rev,
+    scoped_types::{
+        parse_contribution_tag,
+        parse_contribution_update_tag,
+    },
+};
+
+use std::collections::BTreeSet;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    must_get,
+};
+use hdi_extensions::{
+    trace_origin_root,
+    summon_app_entry,
+    // Macros
+    guest_error,
+};
+use coop_content::{
+    EntryTypesUnit,
+    LinkTypes,
+    GroupEntry,
+    ContributionAnchors,
+};
+use coop_content_sdk::{
+    CoopContentSignal,
+};
+
+
+/// Allow other agents to deliver remote signals to this cell
+pub fn grant_remote_signal_capability() -> ExternResult<()> {
+    let mut functions = BTreeSet::new();
+    functions.insert( (zome_info()?.name, "recv_remote_signal".into()) );
+
+    create_cap_grant( CapGrantEntry {
+        tag: "remote_signals".to_string(),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed( functions ),
+    })?;
+
+    Ok(())
+}
+
+fn is_group_entry_type(entry_type: &EntryType) -> bool {
+    match entry_type {
+        EntryType::App(def) => matches!(
+            EntryTypesUnit::try_from( ScopedEntryDefIndex {
+                zome_index: def.zome_index,
+                zome_type: def.entry_index,
+            }),
+            Ok(EntryTypesUnit::Group)
+        ),
+        _ => false,
+    }
+}
+
+fn latest_group(group_id: &ActionHash) -> ExternResult<GroupEntry> {
+    let latest_addr = latest_group_rev( group_id )?;
+
+    must_get( &latest_addr )?.try_into()
+}
+
+/// Build the signal and its remote recipients for a committed action
+///
+/// Returns `None` for actions that are not group or contribution changes.  Links based on archive
+/// anchors are copies made while updating a group so they are not signaled.
+fn action_signal(
+    signed_action: &SignedActionHashed,
+) -> ExternResult<Option<(CoopContentSignal, Vec<AgentPubKey>)>> {
+    let action_hash = signed_action.action_address().to_owned();
+    let author = signed_action.action().author().to_owned();
+
+    Ok(
+        match signed_action.action() {
+            Action::Create(create) if is_group_entry_type( &create.entry_type ) => {
+                let group : GroupEntry = must_get( &action_hash )?.try_into()?;
+
+                Some((
+                    CoopContentSignal::GroupCreated {
+                        group_id: action_hash,
+                        author,
+                    },
+                    group.contributors(),
+                ))
+            },
+            Action::Update(update) if is_group_entry_type( &update.entry_type ) => {
+                let group : GroupEntry = must_get( &action_hash )?.try_into()?;
+                let prev_group : GroupEntry = must_get( &update.original_action_address )?.try_into()?;
+                let mut recipients = prev_group.contributors();
+                recipients.extend( group.contributors() );
+
+                Some((
+                    CoopContentSignal::GroupUpdated {
+                        group_id: trace_origin_root( &action_hash )?.0,
+                        group_rev: action_hash,
+                        prev_rev: update.original_action_address.to_owned(),
+                        author,
+                    },
+                    recipients,
+                ))
+            },
+            Action::CreateLink(create) => match LinkTypes::from_type( create.zome_index, create.link_type )? {
+                Some(LinkTypes::GroupInvite) => {
+                    let group_id = match create.target_address.to_owned().into_action_hash() {
+                        Some(hash) => hash,
+                        None => return Ok(None),
+                    };
+
+                    // The invite base is derived from the invited agent so find the matching one
+                    let mut invited = None;
+                    for agent in latest_group( &group_id )?.contributors() {
+                        let invite_anchor_hash = Path::from(vec![
+                            Component::from( format!("{}:invitations", agent ).as_bytes().to_vec() )
+                        ]).path_entry_hash()?;
+
+                        if create.base_address == invite_anchor_hash.into() {
+                            invited = Some(agent);
+                            break;
+                        }
+                    }
+
+                    invited.map( |agent| (
+                        CoopContentSignal::GroupInvite {
+                            group_id,
+                            agent: agent.to_owned(),
+                            author,
+                        },
+                        vec![ agent ],
+                    ))
+                },
+                Some(LinkTypes::Contribution) | Some(LinkTypes::ContributionUpdate) => {
+                    let anchor : ContributionAnchors = summon_app_entry( &create.base_address )?;
+
+                    if anchor.is_archive() {
+                        return Ok(None);
+                    }
+
+                    let group_id = anchor.group().to_owned();
+                    let recipients = latest_group( &group_id )?.contributors();
+
+                    let signal = match LinkTypes::from_type( create.zome_index, create.link_type )? {
+                        Some(LinkTypes::Contribution) => {
+                            let (content_type, content_base) = match parse_contribution_tag( &create.tag ) {
+                                Some((content_type, content_base)) => (Some(content_type), content_base),
+                                None => (None, None),
+                            };
+
+                            CoopContentSignal::ContributionCreated {
+                                group_id,
+                                author,
+                                content_id: create.target_address.to_owned(),
+                                content_type,
+                                content_base,
+                            }
+                        },
+                        _ => match parse_contribution_update_tag( &create.tag ) {
+                            Some((content_id, content_prev)) => CoopContentSignal::ContributionUpdated {
+                                group_id,
+                                author,
+                                content_id,
+                                content_prev,
+                                content_next: create.target_address.to_owned(),
+                            },
+                            None => return Ok(None),
+                        },
+                    };
+
+                    Some(( signal, recipients ))
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    )
+}
+
+
+/// Emit local signals and notify affected contributors for committed group/content changes
+#[hdk_extern(infallible)]
+pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
+    let agent_id = match agent_id() {
+        Ok(agent_id) => agent_id,
+        Err(err) => {
+            debug!("Unable to get agent info in post commit: {:?}", err );
+            return;
+        },
+    };
+
+    for signed_action in committed_actions {
+        let (signal, recipients) = match action_signal( &signed_action ) {
+            Ok(Some(result)) => result,
+            Ok(None) => continue,
+            Err(err) => {
+                debug!("Unable to build signal for action {}: {:?}", signed_action.action_address(), err );
+                continue;
+            },
+        };
+
+        if let Err(err) = emit_signal( &signal ) {
+            debug!("Failed to emit signal {:?}: {:?}", signal, err );
+        }
+
+        let mut recipients : Vec<AgentPubKey> = recipients.into_iter()
+            .filter( |agent| *agent != agent_id )
+            .collect();
+        recipients.sort();
+        recipients.dedup();
+
+        if !recipients.is_empty() {
+            if let Err(err) = send_remote_signal( &signal, recipients ) {
+                debug!("Failed to send remote signal {:?}: {:?}", signal, err );
+            }
+        }
+    }
+}
+
+
+/// Re-emit signals sent by other agents
+///
+/// The sender must be the signal's author and a contributor of the signaled group.  For group
+/// updates, the contributors of the updated revision are used so that a leaving member's update is
+/// still accepted.
+#[hdk_extern]
+pub fn recv_remote_signal(signal: CoopContentSignal) -> ExternResult<()> {
+    let sender = call_info()?.provenance;
+
+    if *signal.author() != sender {
+        Err(guest_error!(format!(
+            "Remote signal from '{}' claims to be authored by '{}'",
+            sender, signal.author(),
+        )))?
+    }
+
+    let group : GroupEntry = match &signal {
+        CoopContentSignal::GroupUpdated { group_id, prev_rev, .. } => {
+            if trace_origin_root( prev_rev )?.0 != *group_id {
+                Err(guest_error!(format!(
+                    "Remote signal revision '{}' is not a revision of group '{}'",
+                    prev_rev, group_id,
+                )))?
+            }
+
+            must_get( prev_rev )?.try_into()?
+        },
+        _ => latest_group( signal.group_id() )?,
+    };
+
+    if !group.is_contributor( &sender ) {
+        Err(guest_error!(format!(
+            "Ignoring remote signal from '{}' who is not a contributor of group '{}'",
+            sender, signal.group_id(),
+        )))?
+    }
+
+    emit_signal( &signal )
+}
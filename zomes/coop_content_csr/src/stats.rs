@@ -0,0 +1,134 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    check_group_id,
+    GroupLinks,
+    anchor_contribution_links,
+    scoped_types::parse_contribution_tag,
+};
+
+use std::collections::{
+    BTreeMap, HashMap,
+};
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+    follow_evolutions,
+};
+use coop_content::{
+    LinkTypes,
+    GroupEntry,
+    ContributionsAnchorEntry,
+    ArchivedContributionsAnchorEntry,
+};
+use coop_content_sdk::{
+    ContributorStats,
+    GroupStats,
+};
+
+
+/// Count the contribution links of an anchor
+///
+/// Content types are recorded in `content_types` so that group totals can be counted by unique
+/// content ID.  The `timed_links` are used for the last activity time because archive copies are
+/// timestamped when they were copied.
+fn contributor_stats(
+    agent: &AgentPubKey,
+    anchor: &EntryHash,
+    links: &[Link],
+    timed_links: &[Link],
+    content_types: &mut HashMap<AnyLinkableHash, String>,
+) -> ExternResult<ContributorStats> {
+    let mut counts : BTreeMap<String, usize> = BTreeMap::new();
+    let mut updates = 0;
+
+    for link in links {
+        match LinkTypes::from_type( link.zome_index, link.link_type )? {
+            Some(LinkTypes::Contribution) => {
+                if let Some((content_type, _)) = parse_contribution_tag( &link.tag ) {
+                    *counts.entry( content_type.to_owned() ).or_default() += 1;
+                    content_types.insert( link.target.to_owned(), content_type );
+                }
+            },
+            Some(LinkTypes::ContributionUpdate) => updates += 1,
+            _ => continue,
+        }
+    }
+
+    Ok(ContributorStats {
+        agent: agent.to_owned(),
+        anchor: anchor.to_owned(),
+        content_types: counts,
+        updates,
+        last_activity: timed_links.iter()
+            .map( |link| link.timestamp )
+            .max(),
+    })
+}
+
+
+/// Get content counts per type, contributor, and archived member for the latest state of a group
+#[hdk_extern]
+pub fn get_group_stats(group_id: ActionHash) -> ExternResult<GroupStats> {
+    check_group_id( &group_id )?;
+
+    let group_revs = follow_evolutions( &group_id )?;
+    let mut last_activity = must_get( &group_id )?.action().timestamp();
+
+    for group_rev in group_revs.iter() {
+        last_activity = last_activity.max( must_get( group_rev )?.action().timestamp() );
+    }
+
+    let latest_rev = group_revs.last().unwrap_or( &group_id );
+    let mut content_types : HashMap<AnyLinkableHash, String> = HashMap::new();
+    let mut contributors = vec![];
+    let mut archived = vec![];
+
+    for anchor_hash in GroupEntry::group_auth_anchor_hashes( latest_rev )? {
+        let anchor : ContributionsAnchorEntry = must_get( &anchor_hash )?.try_into()?;
+        let links = anchor_contribution_links( &anchor_hash )?;
+
+        contributors.push( contributor_stats(
+            anchor.author(), &anchor_hash, &links, &links, &mut content_types,
+        )? );
+    }
+
+    for archive_hash in GroupEntry::group_auth_archive_anchor_hashes( latest_rev )? {
+        let anchor : ArchivedContributionsAnchorEntry = must_get( &archive_hash )?.try_into()?;
+        let links = anchor_contribution_links( &archive_hash )?;
+
+        // Archived links are copies so the originals are used for their real timestamps
+        let original_anchor = ContributionsAnchorEntry( group_id.to_owned(), anchor.author().to_owned() );
+        let original_links : Vec<Link> = anchor_contribution_links( &hash_entry( &original_anchor )? )?
+            .into_iter()
+            .filter(|link| links.iter().any(|archived| {
+                archived.target == link.target && archived.tag == link.tag
+            }))
+            .collect();
+
+        archived.push( contributor_stats(
+            anchor.author(), &archive_hash, &links, &original_links, &mut content_types,
+        )? );
+    }
+
+    for stats in contributors.iter().chain( archived.iter() ) {
+        if let Some(timestamp) = stats.last_activity {
+            last_activity = last_activity.max( timestamp );
+        }
+    }
+
+    let mut type_counts : BTreeMap<String, usize> = BTreeMap::new();
+
+    for content_type in content_types.into_values() {
+        *type_counts.entry( content_type ).or_default() += 1;
+    }
+
+    Ok(GroupStats {
+        group_id,
+        revisions: group_revs.len(),
+        content_types: type_counts,
+        contributors,
+        archived,
+        last_activity,
+    })
+}
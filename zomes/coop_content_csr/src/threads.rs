@@ -0,0 +1,109 @@
+use crate::{
+    hdk,
+    get_group_content_latest,
+    group_content_targets_shortcuts,
+    group_content_targets_full_trace,
+};
+
+use hdk::prelude::*;
+use coop_content_sdk::{
+    AsOf,
+    GetContentTreeInput,
+    GetGroupContentInput,
+    ContentTreeNode,
+};
+
+
+/// The default number of levels below the root of a content tree
+pub const DEFAULT_TREE_DEPTH : usize = 8;
+
+/// The default number of children per node of a content tree
+pub const DEFAULT_TREE_BREADTH : usize = 100;
+
+
+struct TreeQuery {
+    group_id: ActionHash,
+    content_type: String,
+    max_depth: usize,
+    max_breadth: usize,
+    full_trace: bool,
+    as_of: Option<AsOf>,
+}
+
+/// Get the `(content ID, latest revision)` of each item whose content base is the given ID
+fn child_targets(
+    query: &TreeQuery,
+    parent_id: &AnyLinkableHash,
+) -> ExternResult<Vec<(AnyLinkableHash, AnyLinkableHash)>> {
+    let content_type = Some(query.content_type.to_owned());
+    let content_base = Some(format!("{}", parent_id ));
+    let targets = match query.full_trace {
+        false => group_content_targets_shortcuts( query.group_id.to_owned(), content_type, content_base, query.as_of.to_owned(), false )?,
+        true => group_content_targets_full_trace( query.group_id.to_owned(), content_type, content_base, query.as_of.to_owned(), false )?,
+    };
+
+    // The same content can be linked from both an active and an archive anchor
+    let mut children : Vec<(AnyLinkableHash, AnyLinkableHash)> = vec![];
+
+    for (id, latest) in targets {
+        if !children.iter().any( |(child_id, _)| *child_id == id ) {
+            children.push( (id, latest) );
+        }
+    }
+
+    Ok( children )
+}
+
+fn build_node(
+    query: &TreeQuery,
+    id: AnyLinkableHash,
+    latest: AnyLinkableHash,
+    depth: usize,
+) -> ExternResult<ContentTreeNode> {
+    let mut node = ContentTreeNode {
+        id,
+        latest,
+        children: vec![],
+        truncated: false,
+    };
+    let targets = child_targets( query, &node.id )?;
+
+    if depth >= query.max_depth {
+        node.truncated = !targets.is_empty();
+        return Ok( node );
+    }
+
+    node.truncated = targets.len() > query.max_breadth;
+
+    for (child_id, child_latest) in targets.into_iter().take( query.max_breadth ) {
+        node.children.push( build_node( query, child_id, child_latest, depth + 1 )? );
+    }
+
+    Ok( node )
+}
+
+
+/// Build a tree of the group content whose `content_base` is the ID of its parent
+///
+/// Each node holds the latest revision of its item as seen by the group.
+#[hdk_extern]
+pub fn get_group_content_tree(input: GetContentTreeInput) -> ExternResult<ContentTreeNode> {
+    debug!("Get group content tree: {:?}", input );
+    let latest = get_group_content_latest( GetGroupContentInput {
+        group_id: input.group_id.to_owned(),
+        content_id: input.content_id.to_owned(),
+        full_trace: input.full_trace,
+        as_of: input.as_of.to_owned(),
+        include_proposals: false,
+    })?;
+    let query = TreeQuery {
+        group_id: input.group_id,
+        content_type: input.content_type,
+        max_depth: input.max_depth.unwrap_or( DEFAULT_TREE_DEPTH ),
+        max_breadth: input.max_breadth.unwrap_or( DEFAULT_TREE_BREADTH ),
+        full_trace: input.full_trace.unwrap_or( false ),
+        as_of: input.as_of,
+    };
+
+    build_node( &query, input.content_id, latest, 0 )
+}